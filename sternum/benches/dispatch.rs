@@ -0,0 +1,311 @@
+// Any copyright is dedicated to the Public Domain.
+// https://creativecommons.org/publicdomain/zero/1.0/
+
+//! Compare the `FromStr` implementations generated by each `#[sternum(dispatch = "...")]` mode
+//! on an enum with a large number of variants: the names of the Unicode scripts, along with
+//! several made-up suffixes of each.
+//!
+//! Run with `cargo bench --bench dispatch`.
+
+use std::hint::black_box;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use sternum::Sternum;
+
+macro_rules! scripts {
+    ($($name:ident: $($attr:meta),*;)*) => {
+        $(
+            #[derive(Debug, Eq, PartialEq, Sternum)]
+            #[sternum($($attr),*)]
+            enum $name {
+                Adlam, Ahom, AnatolianHieroglyphs, Arabic, Armenian, Avestan, Balinese, Bamum,
+                BassaVah, Batak, Bengali, Bhaiksuki, Bopomofo, Brahmi, Braille, Buginese, Buhid,
+                CanadianAboriginal, Carian, CaucasianAlbanian, Chakma, Cham, Cherokee, Chorasmian,
+                Common, Coptic, Cuneiform, Cypriot, CyproMinoan, Cyrillic, Deseret, Devanagari,
+                DivesAkuru, Dogra, Duployan, EgyptianHieroglyphs, Elbasan, Elymaic, Ethiopic,
+                Georgian, Glagolitic, Gothic, Grantha, Greek, Gujarati, GunjalaGondi, Gurmukhi, Han,
+                Hangul, HanifiRohingya, Hanunoo, Hatran, Hebrew, Hiragana, ImperialAramaic,
+                Inherited, InscriptionalPahlavi, InscriptionalParthian, Javanese, Kaithi, Kannada,
+                Katakana, Kawi, KayahLi, Kharoshthi, KhitanSmallScript, Khmer, Khojki, Khudawadi,
+                Lao, Latin, Lepcha, Limbu, LinearA, LinearB, Lisu, Lycian, Lydian, Mahajani,
+                Makasar, Malayalam, Mandaic, Manichaean, Marchen, MasaramGondi, Medefaidrin,
+                MeeteiMayek, MendeKikakui, MeroiticCursive, MeroiticHieroglyphs, Miao, Modi,
+                Mongolian, Mro, Multani, Myanmar, Nabataean, NagMundari, Nandinagari, NewTaiLue,
+                Newa, Nko, Nushu, NyiakengPuachueHmong, Ogham, OlChiki, OldHungarian, OldItalic,
+                OldNorthArabian, OldPermic, OldPersian, OldSogdian, OldSouthArabian, OldTurkic,
+                OldUyghur, Oriya, Osage, Osmanya, PahawhHmong, Palmyrene, PauCinHau, PhagsPa,
+                Phoenician, PsalterPahlavi, Rejang, Runic, Samaritan, Saurashtra, Sharada, Shavian,
+                Siddham, SignWriting, Sinhala, Sogdian, SoraSompeng, Soyombo, Sundanese,
+                SylotiNagri, Syriac, Tagalog, Tagbanwa, TaiLe, TaiTham, TaiViet, Takri, Tamil,
+                Tangsa, Tangut, Telugu, Thaana, Thai, Tibetan, Tifinagh, Tirhuta, Toto, Ugaritic,
+                Vai, Vithkuqi, Wancho, WarangCiti, Yezidi, Yi, ZanabazarSquare, AdlamExtended,
+                AhomExtended, AnatolianHieroglyphsExtended, ArabicExtended, ArmenianExtended,
+                AvestanExtended, BalineseExtended, BamumExtended, BassaVahExtended, BatakExtended,
+                BengaliExtended, BhaiksukiExtended, BopomofoExtended, BrahmiExtended,
+                BrailleExtended, BugineseExtended, BuhidExtended, CanadianAboriginalExtended,
+                CarianExtended, CaucasianAlbanianExtended, ChakmaExtended, ChamExtended,
+                CherokeeExtended, ChorasmianExtended, CommonExtended, CopticExtended,
+                CuneiformExtended, CypriotExtended, CyproMinoanExtended, CyrillicExtended,
+                DeseretExtended, DevanagariExtended, DivesAkuruExtended, DograExtended,
+                DuployanExtended, EgyptianHieroglyphsExtended, ElbasanExtended, ElymaicExtended,
+                EthiopicExtended, GeorgianExtended, GlagoliticExtended, GothicExtended,
+                GranthaExtended, GreekExtended, GujaratiExtended, GunjalaGondiExtended,
+                GurmukhiExtended, HanExtended, HangulExtended, HanifiRohingyaExtended,
+                HanunooExtended, HatranExtended, HebrewExtended, HiraganaExtended,
+                ImperialAramaicExtended, InheritedExtended, InscriptionalPahlaviExtended,
+                InscriptionalParthianExtended, JavaneseExtended, KaithiExtended, KannadaExtended,
+                KatakanaExtended, KawiExtended, KayahLiExtended, KharoshthiExtended,
+                KhitanSmallScriptExtended, KhmerExtended, KhojkiExtended, KhudawadiExtended,
+                LaoExtended, LatinExtended, LepchaExtended, LimbuExtended, LinearAExtended,
+                LinearBExtended, LisuExtended, LycianExtended, LydianExtended, MahajaniExtended,
+                MakasarExtended, MalayalamExtended, MandaicExtended, ManichaeanExtended,
+                MarchenExtended, MasaramGondiExtended, MedefaidrinExtended, MeeteiMayekExtended,
+                MendeKikakuiExtended, MeroiticCursiveExtended, MeroiticHieroglyphsExtended,
+                MiaoExtended, ModiExtended, MongolianExtended, MroExtended, MultaniExtended,
+                MyanmarExtended, NabataeanExtended, NagMundariExtended, NandinagariExtended,
+                NewTaiLueExtended, NewaExtended, NkoExtended, NushuExtended,
+                NyiakengPuachueHmongExtended, OghamExtended, OlChikiExtended, OldHungarianExtended,
+                OldItalicExtended, OldNorthArabianExtended, OldPermicExtended, OldPersianExtended,
+                OldSogdianExtended, OldSouthArabianExtended, OldTurkicExtended, OldUyghurExtended,
+                OriyaExtended, OsageExtended, OsmanyaExtended, PahawhHmongExtended,
+                PalmyreneExtended, PauCinHauExtended, PhagsPaExtended, PhoenicianExtended,
+                PsalterPahlaviExtended, RejangExtended, RunicExtended, SamaritanExtended,
+                SaurashtraExtended, SharadaExtended, ShavianExtended, SiddhamExtended,
+                SignWritingExtended, SinhalaExtended, SogdianExtended, SoraSompengExtended,
+                SoyomboExtended, SundaneseExtended, SylotiNagriExtended, SyriacExtended,
+                TagalogExtended, TagbanwaExtended, TaiLeExtended, TaiThamExtended, TaiVietExtended,
+                TakriExtended, TamilExtended, TangsaExtended, TangutExtended, TeluguExtended,
+                ThaanaExtended, ThaiExtended, TibetanExtended, TifinaghExtended, TirhutaExtended,
+                TotoExtended, UgariticExtended, VaiExtended, VithkuqiExtended, WanchoExtended,
+                WarangCitiExtended, YezidiExtended, YiExtended, ZanabazarSquareExtended,
+                AdlamSupplement, AhomSupplement, AnatolianHieroglyphsSupplement, ArabicSupplement,
+                ArmenianSupplement, AvestanSupplement, BalineseSupplement, BamumSupplement,
+                BassaVahSupplement, BatakSupplement, BengaliSupplement, BhaiksukiSupplement,
+                BopomofoSupplement, BrahmiSupplement, BrailleSupplement, BugineseSupplement,
+                BuhidSupplement, CanadianAboriginalSupplement, CarianSupplement,
+                CaucasianAlbanianSupplement, ChakmaSupplement, ChamSupplement, CherokeeSupplement,
+                ChorasmianSupplement, CommonSupplement, CopticSupplement, CuneiformSupplement,
+                CypriotSupplement, CyproMinoanSupplement, CyrillicSupplement, DeseretSupplement,
+                DevanagariSupplement, DivesAkuruSupplement, DograSupplement, DuployanSupplement,
+                EgyptianHieroglyphsSupplement, ElbasanSupplement, ElymaicSupplement,
+                EthiopicSupplement, GeorgianSupplement, GlagoliticSupplement, GothicSupplement,
+                GranthaSupplement, GreekSupplement, GujaratiSupplement, GunjalaGondiSupplement,
+                GurmukhiSupplement, HanSupplement, HangulSupplement, HanifiRohingyaSupplement,
+                HanunooSupplement, HatranSupplement, HebrewSupplement, HiraganaSupplement,
+                ImperialAramaicSupplement, InheritedSupplement, InscriptionalPahlaviSupplement,
+                InscriptionalParthianSupplement, JavaneseSupplement, KaithiSupplement,
+                KannadaSupplement, KatakanaSupplement, KawiSupplement, KayahLiSupplement,
+                KharoshthiSupplement, KhitanSmallScriptSupplement, KhmerSupplement,
+                KhojkiSupplement, KhudawadiSupplement, LaoSupplement, LatinSupplement,
+                LepchaSupplement, LimbuSupplement, LinearASupplement, LinearBSupplement,
+                LisuSupplement, LycianSupplement, LydianSupplement, MahajaniSupplement,
+                MakasarSupplement, MalayalamSupplement, MandaicSupplement, ManichaeanSupplement,
+                MarchenSupplement, MasaramGondiSupplement, MedefaidrinSupplement,
+                MeeteiMayekSupplement, MendeKikakuiSupplement, MeroiticCursiveSupplement,
+                MeroiticHieroglyphsSupplement, MiaoSupplement, ModiSupplement, MongolianSupplement,
+                MroSupplement, MultaniSupplement, MyanmarSupplement, NabataeanSupplement,
+                NagMundariSupplement, NandinagariSupplement, NewTaiLueSupplement, NewaSupplement,
+                NkoSupplement, NushuSupplement, NyiakengPuachueHmongSupplement, OghamSupplement,
+                OlChikiSupplement, OldHungarianSupplement, OldItalicSupplement,
+                OldNorthArabianSupplement, OldPermicSupplement, OldPersianSupplement,
+                OldSogdianSupplement, OldSouthArabianSupplement, OldTurkicSupplement,
+                OldUyghurSupplement, OriyaSupplement, OsageSupplement, OsmanyaSupplement,
+                PahawhHmongSupplement, PalmyreneSupplement, PauCinHauSupplement, PhagsPaSupplement,
+                PhoenicianSupplement, PsalterPahlaviSupplement, RejangSupplement, RunicSupplement,
+                SamaritanSupplement, SaurashtraSupplement, SharadaSupplement, ShavianSupplement,
+                SiddhamSupplement, SignWritingSupplement, SinhalaSupplement, SogdianSupplement,
+                SoraSompengSupplement, SoyomboSupplement, SundaneseSupplement,
+                SylotiNagriSupplement, SyriacSupplement, TagalogSupplement, TagbanwaSupplement,
+                TaiLeSupplement, TaiThamSupplement, TaiVietSupplement, TakriSupplement,
+                TamilSupplement, TangsaSupplement, TangutSupplement, TeluguSupplement,
+                ThaanaSupplement, ThaiSupplement, TibetanSupplement, TifinaghSupplement,
+                TirhutaSupplement, TotoSupplement, UgariticSupplement, VaiSupplement,
+                VithkuqiSupplement, WanchoSupplement, WarangCitiSupplement, YezidiSupplement,
+                YiSupplement, ZanabazarSquareSupplement, AdlamExtendedA, AhomExtendedA,
+                AnatolianHieroglyphsExtendedA, ArabicExtendedA, ArmenianExtendedA, AvestanExtendedA,
+                BalineseExtendedA, BamumExtendedA, BassaVahExtendedA, BatakExtendedA,
+                BengaliExtendedA, BhaiksukiExtendedA, BopomofoExtendedA, BrahmiExtendedA,
+                BrailleExtendedA, BugineseExtendedA, BuhidExtendedA, CanadianAboriginalExtendedA,
+                CarianExtendedA, CaucasianAlbanianExtendedA, ChakmaExtendedA, ChamExtendedA,
+                CherokeeExtendedA, ChorasmianExtendedA, CommonExtendedA, CopticExtendedA,
+                CuneiformExtendedA, CypriotExtendedA, CyproMinoanExtendedA, CyrillicExtendedA,
+                DeseretExtendedA, DevanagariExtendedA, DivesAkuruExtendedA, DograExtendedA,
+                DuployanExtendedA, EgyptianHieroglyphsExtendedA, ElbasanExtendedA, ElymaicExtendedA,
+                EthiopicExtendedA, GeorgianExtendedA, GlagoliticExtendedA, GothicExtendedA,
+                GranthaExtendedA, GreekExtendedA, GujaratiExtendedA, GunjalaGondiExtendedA,
+                GurmukhiExtendedA, HanExtendedA, HangulExtendedA, HanifiRohingyaExtendedA,
+                HanunooExtendedA, HatranExtendedA, HebrewExtendedA, HiraganaExtendedA,
+                ImperialAramaicExtendedA, InheritedExtendedA, InscriptionalPahlaviExtendedA,
+                InscriptionalParthianExtendedA, JavaneseExtendedA, KaithiExtendedA,
+                KannadaExtendedA, KatakanaExtendedA, KawiExtendedA, KayahLiExtendedA,
+                KharoshthiExtendedA, KhitanSmallScriptExtendedA, KhmerExtendedA, KhojkiExtendedA,
+                KhudawadiExtendedA, LaoExtendedA, LatinExtendedA, LepchaExtendedA, LimbuExtendedA,
+                LinearAExtendedA, LinearBExtendedA, LisuExtendedA, LycianExtendedA, LydianExtendedA,
+                MahajaniExtendedA, MakasarExtendedA, MalayalamExtendedA, MandaicExtendedA,
+                ManichaeanExtendedA, MarchenExtendedA, MasaramGondiExtendedA, MedefaidrinExtendedA,
+                MeeteiMayekExtendedA, MendeKikakuiExtendedA, MeroiticCursiveExtendedA,
+                MeroiticHieroglyphsExtendedA, MiaoExtendedA, ModiExtendedA, MongolianExtendedA,
+                MroExtendedA, MultaniExtendedA, MyanmarExtendedA, NabataeanExtendedA,
+                NagMundariExtendedA, NandinagariExtendedA, NewTaiLueExtendedA, NewaExtendedA,
+                NkoExtendedA, NushuExtendedA, NyiakengPuachueHmongExtendedA, OghamExtendedA,
+                OlChikiExtendedA, OldHungarianExtendedA, OldItalicExtendedA,
+                OldNorthArabianExtendedA, OldPermicExtendedA, OldPersianExtendedA,
+                OldSogdianExtendedA, OldSouthArabianExtendedA, OldTurkicExtendedA,
+                OldUyghurExtendedA, OriyaExtendedA, OsageExtendedA, OsmanyaExtendedA,
+                PahawhHmongExtendedA, PalmyreneExtendedA, PauCinHauExtendedA, PhagsPaExtendedA,
+                PhoenicianExtendedA, PsalterPahlaviExtendedA, RejangExtendedA, RunicExtendedA,
+                SamaritanExtendedA, SaurashtraExtendedA, SharadaExtendedA, ShavianExtendedA,
+                SiddhamExtendedA, SignWritingExtendedA, SinhalaExtendedA, SogdianExtendedA,
+                SoraSompengExtendedA, SoyomboExtendedA, SundaneseExtendedA, SylotiNagriExtendedA,
+                SyriacExtendedA, TagalogExtendedA, TagbanwaExtendedA, TaiLeExtendedA,
+                TaiThamExtendedA, TaiVietExtendedA, TakriExtendedA, TamilExtendedA, TangsaExtendedA,
+                TangutExtendedA, TeluguExtendedA, ThaanaExtendedA, ThaiExtendedA, TibetanExtendedA,
+                TifinaghExtendedA, TirhutaExtendedA, TotoExtendedA, UgariticExtendedA, VaiExtendedA,
+                VithkuqiExtendedA, WanchoExtendedA, WarangCitiExtendedA, YezidiExtendedA,
+                YiExtendedA, ZanabazarSquareExtendedA, AdlamExtendedB, AhomExtendedB,
+                AnatolianHieroglyphsExtendedB, ArabicExtendedB, ArmenianExtendedB, AvestanExtendedB,
+                BalineseExtendedB, BamumExtendedB, BassaVahExtendedB, BatakExtendedB,
+                BengaliExtendedB, BhaiksukiExtendedB, BopomofoExtendedB, BrahmiExtendedB,
+                BrailleExtendedB, BugineseExtendedB, BuhidExtendedB, CanadianAboriginalExtendedB,
+                CarianExtendedB, CaucasianAlbanianExtendedB, ChakmaExtendedB, ChamExtendedB,
+                CherokeeExtendedB, ChorasmianExtendedB, CommonExtendedB, CopticExtendedB,
+                CuneiformExtendedB, CypriotExtendedB, CyproMinoanExtendedB, CyrillicExtendedB,
+                DeseretExtendedB, DevanagariExtendedB, DivesAkuruExtendedB, DograExtendedB,
+                DuployanExtendedB, EgyptianHieroglyphsExtendedB, ElbasanExtendedB, ElymaicExtendedB,
+                EthiopicExtendedB, GeorgianExtendedB, GlagoliticExtendedB, GothicExtendedB,
+                GranthaExtendedB, GreekExtendedB, GujaratiExtendedB, GunjalaGondiExtendedB,
+                GurmukhiExtendedB, HanExtendedB, HangulExtendedB, HanifiRohingyaExtendedB,
+                HanunooExtendedB, HatranExtendedB, HebrewExtendedB, HiraganaExtendedB,
+                ImperialAramaicExtendedB, InheritedExtendedB, InscriptionalPahlaviExtendedB,
+                InscriptionalParthianExtendedB, JavaneseExtendedB, KaithiExtendedB,
+                KannadaExtendedB, KatakanaExtendedB, KawiExtendedB, KayahLiExtendedB,
+                KharoshthiExtendedB, KhitanSmallScriptExtendedB, KhmerExtendedB, KhojkiExtendedB,
+                KhudawadiExtendedB, LaoExtendedB, LatinExtendedB, LepchaExtendedB, LimbuExtendedB,
+                LinearAExtendedB, LinearBExtendedB, LisuExtendedB, LycianExtendedB, LydianExtendedB,
+                MahajaniExtendedB, MakasarExtendedB, MalayalamExtendedB, MandaicExtendedB,
+                ManichaeanExtendedB, MarchenExtendedB, MasaramGondiExtendedB, MedefaidrinExtendedB,
+                MeeteiMayekExtendedB, MendeKikakuiExtendedB, MeroiticCursiveExtendedB,
+                MeroiticHieroglyphsExtendedB, MiaoExtendedB, ModiExtendedB, MongolianExtendedB,
+                MroExtendedB, MultaniExtendedB, MyanmarExtendedB, NabataeanExtendedB,
+                NagMundariExtendedB, NandinagariExtendedB, NewTaiLueExtendedB, NewaExtendedB,
+                NkoExtendedB, NushuExtendedB, NyiakengPuachueHmongExtendedB, OghamExtendedB,
+                OlChikiExtendedB, OldHungarianExtendedB, OldItalicExtendedB,
+                OldNorthArabianExtendedB, OldPermicExtendedB, OldPersianExtendedB,
+                OldSogdianExtendedB, OldSouthArabianExtendedB, OldTurkicExtendedB,
+                OldUyghurExtendedB, OriyaExtendedB, OsageExtendedB, OsmanyaExtendedB,
+                PahawhHmongExtendedB, PalmyreneExtendedB, PauCinHauExtendedB, PhagsPaExtendedB,
+                PhoenicianExtendedB, PsalterPahlaviExtendedB, RejangExtendedB, RunicExtendedB,
+                SamaritanExtendedB, SaurashtraExtendedB, SharadaExtendedB, ShavianExtendedB,
+                SiddhamExtendedB, SignWritingExtendedB, SinhalaExtendedB, SogdianExtendedB,
+                SoraSompengExtendedB, SoyomboExtendedB, SundaneseExtendedB, SylotiNagriExtendedB,
+                SyriacExtendedB, TagalogExtendedB, TagbanwaExtendedB, TaiLeExtendedB,
+                TaiThamExtendedB, TaiVietExtendedB, TakriExtendedB, TamilExtendedB, TangsaExtendedB,
+                TangutExtendedB, TeluguExtendedB, ThaanaExtendedB, ThaiExtendedB, TibetanExtendedB,
+                TifinaghExtendedB, TirhutaExtendedB, TotoExtendedB, UgariticExtendedB, VaiExtendedB,
+                VithkuqiExtendedB, WanchoExtendedB, WarangCitiExtendedB, YezidiExtendedB,
+                YiExtendedB, ZanabazarSquareExtendedB, AdlamNumerals, AhomNumerals,
+                AnatolianHieroglyphsNumerals, ArabicNumerals, ArmenianNumerals, AvestanNumerals,
+                BalineseNumerals, BamumNumerals, BassaVahNumerals, BatakNumerals, BengaliNumerals,
+                BhaiksukiNumerals, BopomofoNumerals, BrahmiNumerals, BrailleNumerals,
+                BugineseNumerals, BuhidNumerals, CanadianAboriginalNumerals, CarianNumerals,
+                CaucasianAlbanianNumerals, ChakmaNumerals, ChamNumerals, CherokeeNumerals,
+                ChorasmianNumerals, CommonNumerals, CopticNumerals, CuneiformNumerals,
+                CypriotNumerals, CyproMinoanNumerals, CyrillicNumerals, DeseretNumerals,
+                DevanagariNumerals, DivesAkuruNumerals, DograNumerals, DuployanNumerals,
+                EgyptianHieroglyphsNumerals, ElbasanNumerals, ElymaicNumerals, EthiopicNumerals,
+                GeorgianNumerals, GlagoliticNumerals, GothicNumerals, GranthaNumerals,
+                GreekNumerals, GujaratiNumerals, GunjalaGondiNumerals, GurmukhiNumerals,
+                HanNumerals, HangulNumerals, HanifiRohingyaNumerals, HanunooNumerals,
+                HatranNumerals, HebrewNumerals, HiraganaNumerals, ImperialAramaicNumerals,
+                InheritedNumerals, InscriptionalPahlaviNumerals, InscriptionalParthianNumerals,
+                JavaneseNumerals, KaithiNumerals, KannadaNumerals, KatakanaNumerals, KawiNumerals,
+                KayahLiNumerals, KharoshthiNumerals, KhitanSmallScriptNumerals, KhmerNumerals,
+                KhojkiNumerals, KhudawadiNumerals, LaoNumerals, LatinNumerals, LepchaNumerals,
+                LimbuNumerals, LinearANumerals, LinearBNumerals, LisuNumerals, LycianNumerals,
+                LydianNumerals, MahajaniNumerals, MakasarNumerals, MalayalamNumerals,
+                MandaicNumerals, ManichaeanNumerals, MarchenNumerals, MasaramGondiNumerals,
+                MedefaidrinNumerals, MeeteiMayekNumerals, MendeKikakuiNumerals,
+                MeroiticCursiveNumerals, MeroiticHieroglyphsNumerals, MiaoNumerals, ModiNumerals,
+                MongolianNumerals, MroNumerals, MultaniNumerals, MyanmarNumerals, NabataeanNumerals,
+                NagMundariNumerals, NandinagariNumerals, NewTaiLueNumerals, NewaNumerals,
+                NkoNumerals, NushuNumerals, NyiakengPuachueHmongNumerals, OghamNumerals,
+                OlChikiNumerals, OldHungarianNumerals, OldItalicNumerals, OldNorthArabianNumerals,
+                OldPermicNumerals, OldPersianNumerals, OldSogdianNumerals, OldSouthArabianNumerals,
+                OldTurkicNumerals, OldUyghurNumerals, OriyaNumerals, OsageNumerals, OsmanyaNumerals,
+                PahawhHmongNumerals, PalmyreneNumerals, PauCinHauNumerals, PhagsPaNumerals,
+                PhoenicianNumerals, PsalterPahlaviNumerals, RejangNumerals, RunicNumerals,
+                SamaritanNumerals, SaurashtraNumerals, SharadaNumerals, ShavianNumerals,
+                SiddhamNumerals, SignWritingNumerals, SinhalaNumerals, SogdianNumerals,
+                SoraSompengNumerals, SoyomboNumerals, SundaneseNumerals, SylotiNagriNumerals,
+                SyriacNumerals, TagalogNumerals, TagbanwaNumerals, TaiLeNumerals, TaiThamNumerals,
+                TaiVietNumerals, TakriNumerals, TamilNumerals, TangsaNumerals, TangutNumerals,
+                TeluguNumerals, ThaanaNumerals, ThaiNumerals, TibetanNumerals, TifinaghNumerals,
+                TirhutaNumerals, TotoNumerals, UgariticNumerals, VaiNumerals, VithkuqiNumerals,
+                WanchoNumerals, WarangCitiNumerals, YezidiNumerals, YiNumerals,
+                ZanabazarSquareNumerals,
+            }
+        )*
+    };
+}
+
+scripts! {
+    Match: dispatch = "match";
+    Trie: dispatch = "trie";
+    Phf: dispatch = "phf";
+    MatchIgnoreCase: dispatch = "match", case_insensitive;
+    TrieIgnoreCase: dispatch = "trie", case_insensitive;
+    PhfIgnoreCase: dispatch = "phf", case_insensitive;
+}
+
+const ITERATIONS: u32 = 200;
+const RUNS: u32 = 10;
+
+/// Parse every input `ITERATIONS` times and return the average time per parse of the fastest of
+/// `RUNS` runs.
+fn bench<T>(inputs: &[String]) -> Duration
+where
+    T: FromStr,
+{
+    (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+
+            for _ in 0..ITERATIONS {
+                for input in inputs {
+                    black_box(T::from_str(black_box(input)).is_ok());
+                }
+            }
+
+            start.elapsed() / (ITERATIONS * inputs.len() as u32)
+        })
+        .min()
+        .unwrap()
+}
+
+fn report<T, U, V>(title: &str, inputs: &[String])
+where
+    T: FromStr,
+    U: FromStr,
+    V: FromStr,
+{
+    println!("{}:", title);
+    println!("  match  {:?}", bench::<T>(inputs));
+    println!("  trie   {:?}", bench::<U>(inputs));
+    println!("  phf    {:?}", bench::<V>(inputs));
+}
+
+fn main() {
+    let names: Vec<String> = Match::NAMES.iter().map(|name| name.to_string()).collect();
+    let lowercase: Vec<String> = names.iter().map(|name| name.to_lowercase()).collect();
+
+    // Strings that are not names, which also measure allocating the error.
+    let unknown: Vec<String> = names.iter().map(|name| format!("{}x", name)).collect();
+
+    println!("{} variants", Match::COUNT);
+    println!();
+    report::<Match, Trie, Phf>("case-sensitive, known", &names);
+    report::<Match, Trie, Phf>("case-sensitive, unknown", &unknown);
+    report::<MatchIgnoreCase, TrieIgnoreCase, PhfIgnoreCase>("case-insensitive, known", &lowercase);
+    report::<MatchIgnoreCase, TrieIgnoreCase, PhfIgnoreCase>("case-insensitive, unknown", &unknown);
+}
@@ -95,6 +95,42 @@
 //!    );
 //!    ```
 //!
+//!    Word-aware case styles are also supported. These split names into words at underscores
+//!    and changes in case (keeping acronyms like `HTTPS` and trailing digits like `Utf8`
+//!    together) and then join the words in the given style. They can be chosen with either
+//!    `transform = ...` or a serde-style `rename_all = "..."`:
+//!
+//!    | `transform = ...`      | `rename_all = "..."`     | `HttpsProxy`    |
+//!    |------------------------|--------------------------|-----------------|
+//!    | `uppercase`            | `"UPPERCASE"`            | `HTTPSPROXY`    |
+//!    | `lowercase`            | `"lowercase"`            | `httpsproxy`    |
+//!    | `snake_case`           | `"snake_case"`           | `https_proxy`   |
+//!    | `kebab_case`           | `"kebab-case"`           | `https-proxy`   |
+//!    | `screaming_snake_case` | `"SCREAMING_SNAKE_CASE"` | `HTTPS_PROXY`   |
+//!    | `screaming_kebab_case` | `"SCREAMING-KEBAB-CASE"` | `HTTPS-PROXY`   |
+//!    | `camel_case`           | `"camelCase"`            | `httpsProxy`    |
+//!    | `pascal_case`          | `"PascalCase"`           | `HttpsProxy`    |
+//!    | `train_case`           | `"Train-Case"`           | `Https-Proxy`   |
+//!    | `dot_case`             | `"dot.case"`             | `https.proxy`   |
+//!    | `title_case`           | `"Title Case"`           | `Https Proxy`   |
+//!
+//!    ```
+//!    # use sternum::Sternum;
+//!    #[derive(Debug, Eq, PartialEq, Sternum)]
+//!    #[sternum(rename_all = "kebab-case")]
+//!    enum Kind {
+//!        HTTPSProxy,
+//!        Utf8Error,
+//!    }
+//!
+//!    assert_eq!(Kind::HTTPSProxy.to_string(), "https-proxy");
+//!    assert_eq!(Kind::Utf8Error.to_string(), "utf8-error");
+//!    assert_eq!(str::parse::<Kind>("utf8-error"), Ok(Kind::Utf8Error));
+//!    ```
+//!
+//!    When combined with scoped names, the enumeration and variant names are transformed
+//!    separately (e.g., `Enum::LoudNoises` becomes `enum::loud_noises` in snake case).
+//!
 //!    However, if two or more variants of the enumeration are equal after being transformed, a
 //!    compile error will be produced:
 //!
@@ -103,7 +139,9 @@
 //!    By default, the generated `FromStr` implementations is case-sensitive. By providing the
 //!    `case_insensitive` attribute to the `#[sternum(...)]` attribute will allow for
 //!    case-insensitive parsing of variants. This attribute does not affect the `Display`
-//!    implementation in any way.
+//!    implementation in any way. Parsing does not allocate: ASCII input is compared with the
+//!    names without regard to ASCII case, and other input is compared using Unicode case
+//!    folding, so that `"οδος"` matches `"ΟΔΟΣ"` and `"STRASSE"` matches `"Straße"`.
 //!
 //!    ```
 //!    # use sternum::Sternum;
@@ -122,6 +160,361 @@
 //!    However, if two or more variants of the enumeration are equal in a case-insensitive
 //!    comparision, a compile error will be produced:
 //!
+//! 4. Renaming Variants
+//!
+//!    A single variant can be given a different name by applying the
+//!    `#[sternum(rename = "...")]` attribute to it. The provided name is used verbatim by both
+//!    the `Display` and `FromStr` implementations: it is not scoped or transformed.
+//!
+//!    ```
+//!    # use sternum::{Sternum, UnknownVariantError};
+//!    #[derive(Debug, Eq, PartialEq, Sternum)]
+//!    enum Kind {
+//!        #[sternum(rename = "https-proxy")]
+//!        HttpsProxy,
+//!        Direct,
+//!    }
+//!
+//!    assert_eq!(Kind::HttpsProxy.to_string(), "https-proxy");
+//!    assert_eq!(str::parse::<Kind>("https-proxy"), Ok(Kind::HttpsProxy));
+//!    assert_eq!(
+//!        str::parse::<Kind>("HttpsProxy"),
+//!        Err(UnknownVariantError::new("HttpsProxy")),
+//!    );
+//!    ```
+//!
+//!    If a renamed variant has the same name as another variant, a compile error will be
+//!    produced.
+//!
+//! 5. Aliases
+//!
+//!    Variants can be given additional names with the `#[sternum(alias = "...")]` attribute,
+//!    which may be repeated. Aliases are accepted by the `FromStr` implementation, but the
+//!    `Display` implementation always uses the variant's name. Like renames, aliases are used
+//!    verbatim.
+//!
+//!    ```
+//!    # use sternum::Sternum;
+//!    #[derive(Debug, Eq, PartialEq, Sternum)]
+//!    enum Kind {
+//!        #[sternum(alias = "old_name", alias = "older_name")]
+//!        NewName,
+//!    }
+//!
+//!    assert_eq!(Kind::NewName.to_string(), "NewName");
+//!    assert_eq!(str::parse::<Kind>("NewName"), Ok(Kind::NewName));
+//!    assert_eq!(str::parse::<Kind>("old_name"), Ok(Kind::NewName));
+//!    assert_eq!(str::parse::<Kind>("older_name"), Ok(Kind::NewName));
+//!    ```
+//!
+//!    If an alias is the same as the name or alias of another variant, a compile error will be
+//!    produced.
+//!
+//! 6. Catch-All Variant
+//!
+//!    By default, the generated `FromStr` implementation fails with an
+//!    [`UnknownVariantError`][sternum::UnknownVariantError] for strings that do not match any
+//!    variant. Instead, a single tuple variant with one field can be marked with
+//!    `#[sternum(other)]` to capture these strings. The field must implement `From<&str>` and
+//!    `AsRef<str>` (e.g., `String` or `Box<str>`), and the `Display` implementation prints the
+//!    captured string as-is.
+//!
+//!    ```
+//!    # use sternum::Sternum;
+//!    #[derive(Debug, Eq, PartialEq, Sternum)]
+//!    enum Protocol {
+//!        Http,
+//!        #[sternum(other)]
+//!        Unknown(String),
+//!    }
+//!
+//!    assert_eq!(str::parse::<Protocol>("Http"), Ok(Protocol::Http));
+//!    assert_eq!(str::parse::<Protocol>("Gopher"), Ok(Protocol::Unknown("Gopher".into())));
+//!    assert_eq!(Protocol::Unknown("Gopher".into()).to_string(), "Gopher");
+//!    ```
+//!
+//! 7. Patterns
+//!
+//!    Variants with fields can be displayed and parsed with a template given by
+//!    `#[sternum(pattern = "...")]`. Fields are referred to by name (`{w}`) or, for tuple
+//!    variants, by position (`{0}`), and `{{` and `}}` are literal braces. Every field must
+//!    appear exactly once and each field must be separated from the next by literal text.
+//!
+//!    The `Display` implementation writes the literal text and the `Display` form of each
+//!    field. The `FromStr` implementation matches the literal text exactly and parses each
+//!    field with its own `FromStr` implementation: a field ends at the first occurrence of the
+//!    literal text that follows it. Variants with patterns are tried in declaration order after
+//!    the names of the unit variants.
+//!
+//!    ```
+//!    # use sternum::Sternum;
+//!    #[derive(Debug, Eq, PartialEq, Sternum)]
+//!    enum Setting {
+//!        Auto,
+//!        #[sternum(pattern = "L{0}")]
+//!        Level(u8),
+//!        #[sternum(pattern = "{w}x{h}")]
+//!        Size { w: u32, h: u32 },
+//!    }
+//!
+//!    assert_eq!(Setting::Level(3).to_string(), "L3");
+//!    assert_eq!(Setting::Size { w: 640, h: 480 }.to_string(), "640x480");
+//!    assert_eq!(str::parse::<Setting>("L3"), Ok(Setting::Level(3)));
+//!    assert_eq!(str::parse::<Setting>("640x480"), Ok(Setting::Size { w: 640, h: 480 }));
+//!    assert!(str::parse::<Setting>("L300").is_err());
+//!    ```
+//!
+//! 8. Nested Enums
+//!
+//!    A tuple variant with a single field whose type also derives `Sternum` can be marked with
+//!    `#[sternum(nested)]`. It is displayed as the variant's name, a separator, and the inner
+//!    value. The `FromStr` implementation strips the variant's name and separator from the
+//!    input and delegates the rest to the inner type's `FromStr` implementation. If the inner
+//!    type cannot parse the rest, the resulting error names the entire input, and its `nested`
+//!    method returns the [`NestedVariantError`][sternum::NestedVariantError] that says why.
+//!
+//!    The separator defaults to `::` and can be changed with the `#[sternum(separator = "...")]`
+//!    attribute on the enum, which also applies to scoped names.
+//!
+//!    ```
+//!    # use sternum::Sternum;
+//!    #[derive(Debug, Eq, PartialEq, Sternum)]
+//!    enum TcpEvent {
+//!        Connect,
+//!    }
+//!
+//!    #[derive(Debug, Eq, PartialEq, Sternum)]
+//!    #[sternum(scoped)]
+//!    enum Net {
+//!        #[sternum(nested)]
+//!        Tcp(TcpEvent),
+//!    }
+//!
+//!    assert_eq!(Net::Tcp(TcpEvent::Connect).to_string(), "Net::Tcp::Connect");
+//!    assert_eq!(str::parse::<Net>("Net::Tcp::Connect"), Ok(Net::Tcp(TcpEvent::Connect)));
+//!
+//!    let err = str::parse::<Net>("Net::Tcp::Listen").unwrap_err();
+//!    assert_eq!(err.variant(), "Net::Tcp::Listen");
+//!    assert_eq!(err.nested().unwrap().variant(), "Listen");
+//!    assert_eq!(err.nested().unwrap().type_name(), "TcpEvent");
+//!    ```
+//!
+//! 9. Flattened Enums
+//!
+//!    A tuple variant with a single field whose type also derives `Sternum` can instead be
+//!    marked with `#[sternum(flatten)]` to splice the inner enum's names into the outer enum.
+//!    The `Display` implementation forwards to the inner value, and the `FromStr`
+//!    implementation passes the entire input to the inner type's `FromStr` implementation.
+//!
+//!    If more than one inner enum accepts the same string, the first variant in declaration
+//!    order wins. The names of the outer enum's unit variants always take precedence.
+//!
+//!    ```
+//!    # use sternum::Sternum;
+//!    #[derive(Debug, Eq, PartialEq, Sternum)]
+//!    enum Fruit {
+//!        Apple,
+//!        Orange,
+//!    }
+//!
+//!    #[derive(Debug, Eq, PartialEq, Sternum)]
+//!    enum Color {
+//!        Red,
+//!        Orange,
+//!    }
+//!
+//!    #[derive(Debug, Eq, PartialEq, Sternum)]
+//!    enum Any {
+//!        #[sternum(flatten)]
+//!        Fruit(Fruit),
+//!        #[sternum(flatten)]
+//!        Color(Color),
+//!    }
+//!
+//!    assert_eq!(Any::Color(Color::Red).to_string(), "Red");
+//!    assert_eq!(str::parse::<Any>("Apple"), Ok(Any::Fruit(Fruit::Apple)));
+//!    assert_eq!(str::parse::<Any>("Red"), Ok(Any::Color(Color::Red)));
+//!    assert_eq!(str::parse::<Any>("Orange"), Ok(Any::Fruit(Fruit::Orange)));
+//!    ```
+//!
+//! 10. Dispatch
+//!
+//!     By default, the generated `FromStr` implementation looks up the names of the variants with
+//!     a `match` on the string, which compares the string against the names one at a time. For
+//!     enums with hundreds of variants, the `#[sternum(dispatch = "...")]` attribute selects a
+//!     lookup that does not depend on the number of variants:
+//!
+//!     * `dispatch = "trie"` matches on each byte of the string in turn, and then compares the
+//!       rest of the string with the one name that it can still be; and
+//!     * `dispatch = "phf"` hashes the string into a perfect hash table that is built at compile
+//!       time, and then compares the string with the one name in its slot.
+//!
+//!     `dispatch = "match"` is the default. The choice only affects the names of unit variants
+//!     and their aliases, and the parsed values are the same either way. With
+//!     `#[sternum(case_insensitive)]`, only ASCII input uses the selected lookup. The
+//!     `dispatch` benchmark compares them on an enum with nearly a thousand variants.
+//!
+//!     ```
+//!     # use sternum::Sternum;
+//!     #[derive(Debug, Eq, PartialEq, Sternum)]
+//!     #[sternum(dispatch = "phf")]
+//!     enum Script {
+//!         Latin,
+//!         Greek,
+//!         Cyrillic,
+//!     }
+//!
+//!     assert_eq!(str::parse::<Script>("Greek"), Ok(Script::Greek));
+//!     ```
+//!
+//! 11. Compact
+//!
+//!     The generated implementations normally have a `match` arm for each variant, which slows
+//!     down compilation of enums with thousands of variants. The `#[sternum(compact)]` attribute
+//!     instead generates a single table of names: the `Display` implementation and `as_str`
+//!     index [`NAMES`][sternum::Sternum::NAMES] by the variant's discriminant, and the `FromStr`
+//!     implementation binary searches a sorted table of the names and aliases. Names, aliases,
+//!     transforms, and case-insensitivity behave exactly as they otherwise would.
+//!
+//!     A compact enum must implement `Copy`, and its variants must all be unit variants without
+//!     explicit discriminants. It cannot also have `#[sternum(dispatch = "...")]`.
+//!
+//!     ```
+//!     # use sternum::Sternum;
+//!     #[derive(Clone, Copy, Debug, Eq, PartialEq, Sternum)]
+//!     #[sternum(compact, case_insensitive, rename_all = "snake_case")]
+//!     enum ErrorCode {
+//!         NotFound,
+//!         #[sternum(alias = "denied")]
+//!         PermissionDenied,
+//!     }
+//!
+//!     assert_eq!(ErrorCode::NotFound.to_string(), "not_found");
+//!     assert_eq!(str::parse::<ErrorCode>("PERMISSION_DENIED"), Ok(ErrorCode::PermissionDenied));
+//!     assert_eq!(str::parse::<ErrorCode>("Denied"), Ok(ErrorCode::PermissionDenied));
+//!     ```
+//!
+//! 12. Abbreviations
+//!
+//!     The `#[sternum(abbrev)]` attribute generates a `parse_abbrev` method that also accepts
+//!     any prefix of the name of a unit variant, as long as it is the prefix of only one name.
+//!     Names and aliases are always parsed as themselves, even when they are also a prefix of
+//!     another name. A prefix of more than one name is rejected with an
+//!     [`AbbrevError::Ambiguous`][sternum::AbbrevError::Ambiguous] error that lists the
+//!     candidates. With `#[sternum(case_insensitive)]`, prefixes are compared
+//!     case-insensitively, and with `#[sternum(trim)]`, they are trimmed first.
+//!
+//!     The generated `min_abbrev` method returns the shortest abbreviation that `parse_abbrev`
+//!     accepts for each of the [`NAMES`][sternum::Sternum::NAMES] of the enum, which can be
+//!     used to highlight them in help text. An enum with a `#[sternum(other)]` variant cannot
+//!     have abbreviations, and neither can an enum with `#[sternum(loose)]` or
+//!     `#[sternum(normalize = ...)]`.
+//!
+//!     ```
+//!     # use sternum::{AbbrevError, Sternum};
+//!     #[derive(Debug, Eq, PartialEq, Sternum)]
+//!     #[sternum(abbrev, rename_all = "lowercase")]
+//!     enum Command {
+//!         Status,
+//!         Stash,
+//!         Commit,
+//!     }
+//!
+//!     assert_eq!(Command::parse_abbrev("stat"), Ok(Command::Status));
+//!     assert_eq!(Command::parse_abbrev("c"), Ok(Command::Commit));
+//!     assert_eq!(Command::min_abbrev(), &["stat", "stas", "c"]);
+//!
+//!     match Command::parse_abbrev("st") {
+//!         Err(AbbrevError::Ambiguous(err)) => assert_eq!(err.candidates, &["stash", "status"]),
+//!         _ => unreachable!(),
+//!     }
+//!     ```
+//!
+//! 13. Loose
+//!
+//!     The `#[sternum(loose)]` attribute makes the `FromStr` implementation ignore `-`, `_`, `.`,
+//!     and whitespace and compare the rest of the string case-insensitively, so that `FooBar`,
+//!     `foo-bar`, `foo_bar`, and `FOO BAR` all parse as the same variant. The `Display`
+//!     implementation still uses the variant's name as it is.
+//!
+//!     This applies to the names and aliases of unit variants. Names that are the same once their
+//!     separators are removed and their case is folded are rejected at compile time. A loose enum
+//!     cannot also have `#[sternum(dispatch = "...")]`.
+//!
+//!     ```
+//!     # use sternum::Sternum;
+//!     #[derive(Debug, Eq, PartialEq, Sternum)]
+//!     #[sternum(loose)]
+//!     enum Kind {
+//!         FooBar,
+//!     }
+//!
+//!     assert_eq!(Kind::FooBar.to_string(), "FooBar");
+//!     assert_eq!(str::parse::<Kind>("foo-bar"), Ok(Kind::FooBar));
+//!     assert_eq!(str::parse::<Kind>("FOO BAR"), Ok(Kind::FooBar));
+//!     assert_eq!(str::parse::<Kind>("foo.bar"), Ok(Kind::FooBar));
+//!     ```
+//!
+//! 14. Normalize
+//!
+//!     The `#[sternum(normalize = path::to::function)]` attribute applies a function with the
+//!     signature `fn(&str) -> Cow<str>` to the string before the `FromStr` implementation looks
+//!     it up in the names of the unit variants, for preprocessing that the other attributes do
+//!     not cover, such as stripping a prefix or Unicode normalization. The names and aliases of
+//!     unit variants go through the same function, so that they are compared in the same form as
+//!     the string. Variants with patterns, nested and flattened variants, and the
+//!     `#[sternum(other)]` variant are given the string as it was before it was normalized, as is
+//!     `UnknownVariantError`.
+//!
+//!     The names are normalized once, the first time a string is parsed, or each time on targets
+//!     without atomic compare-and-swap. Since the function only runs then, names of different
+//!     variants that are the same once normalized cannot be rejected at compile time. Instead,
+//!     the variant declared first wins. A normalized enum
+//!     cannot also have `#[sternum(loose)]` or `#[sternum(dispatch = "...")]`, and requires the
+//!     `alloc` feature.
+//!
+//!     ```
+//!     # use std::borrow::Cow;
+//!     # use sternum::Sternum;
+//!     fn strip_v(s: &str) -> Cow<'_, str> {
+//!         Cow::Borrowed(s.strip_prefix('v').unwrap_or(s))
+//!     }
+//!
+//!     #[derive(Debug, Eq, PartialEq, Sternum)]
+//!     #[sternum(normalize = strip_v)]
+//!     enum Version {
+//!         #[sternum(rename = "v1")]
+//!         V1,
+//!         #[sternum(rename = "v2")]
+//!         V2,
+//!     }
+//!
+//!     assert_eq!(Version::V1.to_string(), "v1");
+//!     assert_eq!(str::parse::<Version>("2"), Ok(Version::V2));
+//!     assert_eq!(str::parse::<Version>("v2"), Ok(Version::V2));
+//!     ```
+//!
+//! 15. Trim
+//!
+//!     The `#[sternum(trim)]` attribute makes the `FromStr` implementation remove leading and
+//!     trailing whitespace, as defined by [`str::trim`][str::trim], before parsing the string.
+//!     This is useful for values read from CSV cells, environment variables, or HTTP headers.
+//!     `UnknownVariantError` keeps the string as it was before it was trimmed. With
+//!     `#[sternum(normalize = ...)]`, the string is trimmed first.
+//!
+//!     ```
+//!     # use sternum::{Sternum, UnknownVariantError};
+//!     #[derive(Debug, Eq, PartialEq, Sternum)]
+//!     #[sternum(trim)]
+//!     enum Kind {
+//!         Foo,
+//!     }
+//!
+//!     assert_eq!(str::parse::<Kind>("Foo\n"), Ok(Kind::Foo));
+//!     assert_eq!(str::parse::<Kind>("\u{3000}Foo "), Ok(Kind::Foo));
+//!     assert_eq!(str::parse::<Kind>(" Bar\n"), Err(UnknownVariantError::new(" Bar\n")));
+//!     ```
+//!
 //! ## `FromStr`
 //!
 //! Each `FromStr` implementation will use the
@@ -139,23 +532,284 @@
 //!
 //! ```
 //!
+//! The error's `Display` implementation lists the [`NAMES`][sternum::Sternum::NAMES] of the
+//! enum that were expected (up to a limit), and when the string is a likely typo of one of them,
+//! the error suggests the closest ones:
+//!
+//! ```
+//! # use sternum::Sternum;
+//! #[derive(Debug, Eq, PartialEq, Sternum)]
+//! #[sternum(rename_all = "lowercase")]
+//! enum Command {
+//!     Status,
+//!     Commit,
+//! }
+//!
+//! let err = str::parse::<Command>("comit").unwrap_err();
+//! assert_eq!(err.suggestions(), &["commit"]);
+//! assert_eq!(
+//!     err.to_string(),
+//!     "Could not parse `comit' as type Command: unknown variant \
+//!      (expected one of: `status', `commit'); did you mean `commit'?",
+//! );
+//! ```
+//!
+//! ## `TryFrom`
+//!
+//! `TryFrom<&str>`, `TryFrom<String>`, and `TryFrom<Cow<str>>` are also implemented. They
+//! parse exactly like the `FromStr` implementation and share its error type.
+//!
+//! ```
+//! # use std::convert::TryFrom;
+//! # use sternum::{Sternum, UnknownVariantError};
+//! #[derive(Debug, Eq, PartialEq, Sternum)]
+//! enum Enum {
+//!     Foo,
+//! }
+//!
+//! assert_eq!(Enum::try_from("Foo"), Ok(Enum::Foo));
+//! assert_eq!(Enum::try_from(String::from("Foo")), Ok(Enum::Foo));
+//! assert_eq!(Enum::try_from("unknown"), Err(UnknownVariantError::new("unknown")));
+//! ```
+//!
+//! ## Variants and Names
+//!
+//! The [`Sternum`][sternum::Sternum] trait provides the unit variants of the enum, their
+//! names (as they are displayed), and the number of them:
+//!
+//! ```
+//! # use sternum::Sternum;
+//! #[derive(Debug, Eq, PartialEq, Sternum)]
+//! #[sternum(rename_all = "snake_case")]
+//! enum Level {
+//!     Debug,
+//!     Info,
+//!     MostlyHarmless,
+//! }
+//!
+//! assert_eq!(Level::VARIANTS, &[Level::Debug, Level::Info, Level::MostlyHarmless]);
+//! assert_eq!(Level::NAMES, &["debug", "info", "mostly_harmless"]);
+//! assert_eq!(Level::COUNT, 3);
+//! ```
+//!
+//! ## `as_str`
+//!
+//! When every variant of the enum is a unit variant, an inherent `const fn as_str(&self) ->
+//! &'static str` is generated that returns the same name as the `Display` implementation
+//! without allocating. The enum also implements `AsRef<str>`, and `&'static str` and `String`
+//! implement `From` the enum.
+//!
+//! ```
+//! # use sternum::Sternum;
+//! #[derive(Debug, Eq, PartialEq, Sternum)]
+//! #[sternum(rename_all = "kebab-case")]
+//! enum Kind {
+//!     HttpsProxy,
+//! }
+//!
+//! const NAME: &str = Kind::HttpsProxy.as_str();
+//!
+//! assert_eq!(NAME, "https-proxy");
+//! assert_eq!(Kind::HttpsProxy.as_ref(), "https-proxy");
+//! assert_eq!(String::from(Kind::HttpsProxy), "https-proxy");
+//! ```
+//!
+//! ## Completion
+//!
+//! An inherent `complete(prefix: &str)` function is generated that iterates over the unit
+//! variants whose names start with `prefix`, along with their names, in declaration order.
+//! With `#[sternum(case_insensitive)]`, the prefix is compared case-insensitively, and must end
+//! on a character of the name, so `"I"` does not complete `"İx"`, whose `İ` folds to `i̇`.
+//! Aliases are not completed, and enums without unit variants do not have a `complete`
+//! function.
+//!
+//! ```
+//! # use sternum::Sternum;
+//! #[derive(Debug, Eq, PartialEq, Sternum)]
+//! #[sternum(rename_all = "lowercase")]
+//! enum Command {
+//!     Status,
+//!     Commit,
+//!     Stash,
+//! }
+//!
+//! let completions: Vec<_> = Command::complete("st").collect();
+//!
+//! assert_eq!(
+//!     completions,
+//!     &[(Command::Status, "status"), (Command::Stash, "stash")],
+//! );
+//! ```
+//!
+//! ## Parsing prefixes
+//!
+//! An inherent `parse_prefix(s: &str)` function is generated that parses the name or alias of a
+//! unit variant at the start of `s` and returns the variant along with the rest of `s`, which
+//! makes it easy to use an enum as a table of keywords or operators in a tokenizer. When more
+//! than one name matches, the longest one wins. With `#[sternum(case_insensitive)]`, names are
+//! matched case-insensitively. Like `complete`, it is only generated for enums with unit
+//! variants.
+//!
+//! ```
+//! # use sternum::Sternum;
+//! #[derive(Debug, Eq, PartialEq, Sternum)]
+//! enum Op {
+//!     #[sternum(rename = ">")]
+//!     Gt,
+//!     #[sternum(rename = ">=")]
+//!     Ge,
+//! }
+//!
+//! assert_eq!(Op::parse_prefix(">= 3"), Some((Op::Ge, " 3")));
+//! assert_eq!(Op::parse_prefix("> 3"), Some((Op::Gt, " 3")));
+//! assert_eq!(Op::parse_prefix("< 3"), None);
+//! ```
+//!
+//! ## Searching
+//!
+//! The `#[sternum(find_iter)]` attribute generates an inherent `find_iter(haystack: &str)`
+//! function that iterates over the non-overlapping occurrences of the names of the unit variants
+//! in `haystack`, along with their byte ranges. When occurrences overlap, the one that starts
+//! first wins, and then the longest one. The search uses an Aho-Corasick automaton that is built
+//! at compile time, so its speed does not depend on the number of variants. To find the longest
+//! occurrence, it may read past the end of one before returning it, and then reads those bytes
+//! again when searching for the next one, at most the length of the longest name each time. The
+//! automaton adds to the compile time and size of the crate, which is why it is opt-in. With
+//! `#[sternum(case_insensitive)]`, names are found case-insensitively. Like `complete`, it is
+//! only generated for enums with unit variants.
+//!
+//! ```
+//! # use sternum::Sternum;
+//! #[derive(Debug, Eq, PartialEq, Sternum)]
+//! #[sternum(case_insensitive, find_iter, rename_all = "lowercase")]
+//! enum Status {
+//!     Ok,
+//!     Failed,
+//!     FailedPermanently,
+//! }
+//!
+//! let found: Vec<_> = Status::find_iter("job 1: OK, job 2: failedpermanently").collect();
+//!
+//! assert_eq!(found, &[(7..9, Status::Ok), (18..35, Status::FailedPermanently)]);
+//! ```
+//!
+//! ## Serde
+//!
+//! With the `serde` feature enabled, the `#[sternum(serde)]` attribute implements serde's
+//! `Serialize` and `Deserialize` traits for the enum. Values are serialized as their `Display`
+//! string and deserialized with the same rules as the `FromStr` implementation, so transforms,
+//! scoping, aliases, and case-insensitivity all apply. Deserializing borrows the input string
+//! rather than copying it, and unknown values are reported with serde's `unknown_variant` error,
+//! which lists the [`NAMES`][sternum::Sternum::NAMES] of the enum. Enums without the attribute
+//! are free to implement the serde traits themselves.
+//!
+//! ```
+//! # #[cfg(feature = "serde")] {
+//! # use sternum::Sternum;
+//! #[derive(Debug, Eq, PartialEq, Sternum)]
+//! #[sternum(serde, rename_all = "snake_case")]
+//! enum Level {
+//!     Debug,
+//!     MostlyHarmless,
+//! }
+//!
+//! assert_eq!(serde_json::to_string(&Level::MostlyHarmless).unwrap(), "\"mostly_harmless\"");
+//! assert_eq!(serde_json::from_str::<Level>("\"debug\"").unwrap(), Level::Debug);
+//! # }
+//! ```
+//!
+//! ## Clap
+//!
+//! With the `clap` feature enabled, the `#[sternum(clap)]` attribute implements clap's
+//! `ValueEnum` trait for the enum, which must also implement `Clone`. The possible values are
+//! the unit variants of the enum, named as they are displayed. Aliases become clap aliases and
+//! the first paragraph of each variant's doc comment becomes its help text.
+//!
+//! ```
+//! # #[cfg(feature = "clap")] {
+//! # use clap::ValueEnum;
+//! # use sternum::Sternum;
+//! #[derive(Clone, Debug, Eq, PartialEq, Sternum)]
+//! #[sternum(clap, rename_all = "snake_case")]
+//! enum Level {
+//!     /// Everything.
+//!     Debug,
+//!     #[sternum(alias = "mh")]
+//!     MostlyHarmless,
+//! }
+//!
+//! let value = Level::MostlyHarmless.to_possible_value().unwrap();
+//! assert!(value.matches("mostly_harmless", false));
+//! assert!(value.matches("mh", false));
+//! # }
+//! ```
+//!
+//! ## `no_std`
+//!
+//! Sternum and the code it generates only require `core`. The `std` feature, which is enabled
+//! by default, implements `std::error::Error` for
+//! [`UnknownVariantError`][sternum::UnknownVariantError]. Without it, the `alloc` feature
+//! enables the parts of Sternum that allocate:
+//!
+//! * the `TryFrom<String>`, `TryFrom<Cow<str>>`, and `From<Enum> for String` implementations;
+//! * the suggestions of [`UnknownVariantError`][sternum::UnknownVariantError];
+//! * the `#[sternum(normalize = ...)]` attribute; and
+//! * keeping the entire string that could not be parsed in
+//!   [`UnknownVariantError`][sternum::UnknownVariantError]. Without `alloc`, it keeps a
+//!   [`TruncatedStr`][sternum::TruncatedStr] instead.
+//!
+//! The `clap` feature requires `std`.
+//!
 //! [std::fmt::Display]: https://doc.rust-lang.org/std/fmt/trait.Display.html
 //! [std::str::FromStr]: https://doc.rust-lang.org/std/str/trait.FromStr.html
 //! [std::str::FromStr::Err]: https://doc.rust-lang.org/std/str/trait.FromStr.html#associatedtype.Err
+//! [str::trim]: https://doc.rust-lang.org/std/primitive.str.html#method.trim
 //!
+//! [sternum::AbbrevError::Ambiguous]: enum.AbbrevError.html#variant.Ambiguous
+//! [sternum::NestedVariantError]: struct.NestedVariantError.html
+//! [sternum::Sternum]: trait.Sternum.html
+//! [sternum::Sternum::NAMES]: trait.Sternum.html#associatedconstant.NAMES
+//! [sternum::TruncatedStr]: struct.TruncatedStr.html
 //! [sternum::UnknownVariantError]: struct.UnknownVariantError.html
 
-use std::error;
-use std::fmt;
-use std::marker::PhantomData;
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::cmp;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::Deref;
+use core::str;
 
 pub use sternum_derive::Sternum;
 
+#[doc(hidden)]
+#[path = "private.rs"]
+pub mod __private;
+
+mod fold;
+
+/// How errors keep the string that could not be parsed.
+#[cfg(feature = "alloc")]
+type Input = String;
+
+/// How errors keep the string that could not be parsed.
+#[cfg(not(feature = "alloc"))]
+type Input = TruncatedStr;
+
 #[derive(Eq, PartialEq)]
 /// An error indicating that a string could not be parsed as a `T` variant.
 pub struct UnknownVariantError<T> {
-    /// The string that could not be parsed.
-    pub variant: String,
+    variant: Input,
+    nested: Option<__private::Nested>,
     _ty: PhantomData<T>,
 }
 
@@ -164,13 +818,161 @@ impl<T> UnknownVariantError<T> {
     pub fn new(variant: &str) -> Self {
         UnknownVariantError {
             variant: variant.into(),
+            nested: None,
             _ty: PhantomData,
         }
     }
+
+    /// The string that could not be parsed.
+    ///
+    /// Without the `alloc` feature, this is truncated to
+    /// [`TruncatedStr::CAPACITY`][TruncatedStr::CAPACITY] bytes.
+    ///
+    /// [TruncatedStr::CAPACITY]: struct.TruncatedStr.html#associatedconstant.CAPACITY
+    pub fn variant(&self) -> &str {
+        &self.variant
+    }
+
+    /// Why the string could not be parsed as the inner value of a `#[sternum(nested)]` variant,
+    /// if it starts with the name of one.
+    ///
+    /// When nested variants are nested in turn, this describes the innermost one.
+    pub fn nested(&self) -> Option<NestedVariantError<'_>> {
+        self.nested.map(|nested| NestedVariantError {
+            // Without `alloc`, the rest of the string is truncated along with the string.
+            variant: self.variant.get(nested.start..).unwrap_or(""),
+            type_name: nested.type_name,
+            names: nested.names,
+            unit_only: nested.unit_only,
+        })
+    }
+}
+
+impl<T> UnknownVariantError<T>
+where
+    T: Sternum,
+{
+    /// The names that the string was expected to be, i.e., the
+    /// [`NAMES`][Sternum::NAMES] of `T`.
+    ///
+    /// Strings that parse as variants with fields are not included.
+    ///
+    /// [Sternum::NAMES]: trait.Sternum.html#associatedconstant.NAMES
+    pub fn expected(&self) -> &'static [&'static str] {
+        T::NAMES
+    }
+
+    /// The names of the variants that the string may have been a typo of, from closest to
+    /// furthest.
+    ///
+    /// Names are compared with the string by case-insensitive edit distance, and only those
+    /// that are close enough to be a plausible typo are included. Ties are in declaration order.
+    ///
+    /// This requires the `alloc` feature.
+    #[cfg(feature = "alloc")]
+    pub fn suggestions(&self) -> Vec<&'static str> {
+        suggestions(&self.variant, T::NAMES)
+    }
+}
+
+/// An error indicating that the inner value of a `#[sternum(nested)]` variant could not be
+/// parsed.
+///
+/// This is kept in an [`UnknownVariantError`][UnknownVariantError] for the outer enum.
+///
+/// [UnknownVariantError]: struct.UnknownVariantError.html
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct NestedVariantError<'a> {
+    variant: &'a str,
+    type_name: &'static str,
+    names: &'static [&'static str],
+    unit_only: bool,
+}
+
+impl<'a> NestedVariantError<'a> {
+    /// The rest of the string after the name of the nested variant, which could not be parsed.
+    ///
+    /// Without the `alloc` feature, this is truncated along with
+    /// [`UnknownVariantError::variant`][UnknownVariantError::variant].
+    ///
+    /// [UnknownVariantError::variant]: struct.UnknownVariantError.html#method.variant
+    pub fn variant(&self) -> &'a str {
+        self.variant
+    }
+
+    /// The name of the inner type.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// The names that the rest of the string was expected to be, i.e., the
+    /// [`NAMES`][Sternum::NAMES] of the inner type.
+    ///
+    /// [Sternum::NAMES]: trait.Sternum.html#associatedconstant.NAMES
+    pub fn expected(&self) -> &'static [&'static str] {
+        self.names
+    }
+
+    /// The names of the inner variants that the rest of the string may have been a typo of, as
+    /// for [`UnknownVariantError::suggestions`][UnknownVariantError::suggestions].
+    ///
+    /// This requires the `alloc` feature.
+    ///
+    /// [UnknownVariantError::suggestions]: struct.UnknownVariantError.html#method.suggestions
+    #[cfg(feature = "alloc")]
+    pub fn suggestions(&self) -> Vec<&'static str> {
+        suggestions(self.variant, self.names)
+    }
+}
+
+/// The `names` that `variant` may have been a typo of, from closest to furthest.
+#[cfg(feature = "alloc")]
+fn suggestions(variant: &str, names: &'static [&'static str]) -> Vec<&'static str> {
+    let max_distance = cmp::max(variant.chars().count(), 3) / 3;
+
+    let mut suggestions: Vec<_> = names
+        .iter()
+        .filter_map(|name| {
+            let distance = edit_distance(variant, name);
+
+            // A name that would have to be replaced entirely is not a plausible typo.
+            if distance <= max_distance && distance < name.chars().count() {
+                Some((distance, *name))
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    suggestions.sort_by_key(|&(distance, _)| distance);
+    suggestions.into_iter().map(|(_, name)| name).collect()
 }
 
 /// The Sternum trait
-pub trait Sternum {
+pub trait Sternum: Sized + 'static {
+    /// The unit variants of the type, in declaration order.
+    ///
+    /// Variants with fields (i.e., `#[sternum(other)]`, `#[sternum(pattern = "...")]`,
+    /// `#[sternum(nested)]`, and `#[sternum(flatten)]` variants) are not included.
+    const VARIANTS: &'static [Self];
+
+    /// The names of the variants in [`VARIANTS`][Sternum::VARIANTS], as they are displayed.
+    ///
+    /// [Sternum::VARIANTS]: trait.Sternum.html#associatedconstant.VARIANTS
+    const NAMES: &'static [&'static str];
+
+    /// The number of variants in [`VARIANTS`][Sternum::VARIANTS].
+    ///
+    /// [Sternum::VARIANTS]: trait.Sternum.html#associatedconstant.VARIANTS
+    const COUNT: usize = Self::VARIANTS.len();
+
+    /// Whether every variant of the type is a unit variant, which `UnknownVariantError` uses to
+    /// describe the expected values.
+    ///
+    /// This is not part of the public API and is set by `#[derive(Sternum)]`.
+    #[doc(hidden)]
+    const UNIT_ONLY: bool;
+
     /// The name of the type.
     ///
     /// This is used inside the `Debug` and `Display` implementations of
@@ -187,15 +989,23 @@ where
     // We cannot derive Debug for UnknownVariantError<T> since T may not implement Debug, but we
     // don't actually need to debug print any T values.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.debug_struct(&format!(
-            "UnknownVariantError<{}>",
-            <T as Sternum>::type_name(),
-        ))
-        .field("variant", &self.variant)
-        .finish()
+        // The name of the struct is written out first so that it can include the name of `T`
+        // without allocating.
+        write!(f, "UnknownVariantError<{}>", <T as Sternum>::type_name())?;
+
+        f.debug_struct("")
+            .field("variant", &self.variant)
+            .field("nested", &self.nested())
+            .finish()
     }
 }
 
+/// The most names that the `Display` implementation of
+/// [`UnknownVariantError`][UnknownVariantError] will list as expected.
+///
+/// [UnknownVariantError]: struct.UnknownVariantError.html
+const MAX_EXPECTED: usize = 8;
+
 impl<T> fmt::Display for UnknownVariantError<T>
 where
     T: Sternum,
@@ -206,8 +1016,282 @@ where
             "Could not parse `{}' as type {}: unknown variant",
             self.variant,
             <T as Sternum>::type_name()
-        )
+        )?;
+
+        match self.nested() {
+            Some(nested) => {
+                write!(f, " `{}' of type {}", nested.variant, nested.type_name)?;
+                fmt_expected(f, nested.variant, nested.names, nested.unit_only)
+            }
+            None => fmt_expected(f, &self.variant, T::NAMES, T::UNIT_ONLY),
+        }
+    }
+}
+
+/// Write the `names` that `variant` was expected to be, and the closest one to it.
+#[cfg_attr(not(feature = "alloc"), allow(unused_variables))]
+fn fmt_expected(
+    f: &mut fmt::Formatter,
+    variant: &str,
+    names: &'static [&'static str],
+    unit_only: bool,
+) -> fmt::Result {
+    if !names.is_empty() {
+        f.write_str(" (expected one of: ")?;
+
+        for (i, name) in names.iter().take(MAX_EXPECTED).enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+
+            write!(f, "`{}'", name)?;
+        }
+
+        if names.len() > MAX_EXPECTED {
+            write!(f, ", and {} more", names.len() - MAX_EXPECTED)?;
+        }
+
+        // The names do not cover the strings that variants with fields parse.
+        if !unit_only {
+            f.write_str(", or a value of a variant with fields")?;
+        }
+
+        f.write_str(")")?;
+    }
+
+    #[cfg(feature = "alloc")]
+    {
+        if let Some(suggestion) = suggestions(variant, names).first() {
+            write!(f, "; did you mean `{}'?", suggestion)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "std")]
+impl<T> std::error::Error for UnknownVariantError<T> where T: Sternum {}
+
+/// An error indicating that a string could not be parsed as a `T` variant or as an abbreviation
+/// of one by the `parse_abbrev` method of a `#[sternum(abbrev)]` enum.
+#[derive(Eq, PartialEq)]
+pub enum AbbrevError<T> {
+    /// The string is not a variant or an abbreviation of one.
+    Unknown(UnknownVariantError<T>),
+
+    /// The string is an abbreviation of more than one variant.
+    Ambiguous(AmbiguousAbbrevError<T>),
+}
+
+impl<T> fmt::Debug for AbbrevError<T>
+where
+    T: Sternum,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AbbrevError::Unknown(err) => f.debug_tuple("Unknown").field(err).finish(),
+            AbbrevError::Ambiguous(err) => f.debug_tuple("Ambiguous").field(err).finish(),
+        }
+    }
+}
+
+impl<T> fmt::Display for AbbrevError<T>
+where
+    T: Sternum,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AbbrevError::Unknown(err) => fmt::Display::fmt(err, f),
+            AbbrevError::Ambiguous(err) => fmt::Display::fmt(err, f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> std::error::Error for AbbrevError<T> where T: Sternum {}
+
+/// An error indicating that a string is an abbreviation of more than one `T` variant.
+#[derive(Eq, PartialEq)]
+pub struct AmbiguousAbbrevError<T> {
+    abbrev: Input,
+
+    /// The names of the variants that the string is an abbreviation of, in sorted order.
+    pub candidates: &'static [&'static str],
+
+    _ty: PhantomData<T>,
+}
+
+impl<T> AmbiguousAbbrevError<T> {
+    /// Generate a new error.
+    pub fn new(abbrev: &str, candidates: &'static [&'static str]) -> Self {
+        AmbiguousAbbrevError {
+            abbrev: abbrev.into(),
+            candidates,
+            _ty: PhantomData,
+        }
+    }
+
+    /// The abbreviation.
+    ///
+    /// Without the `alloc` feature, this is truncated to
+    /// [`TruncatedStr::CAPACITY`][TruncatedStr::CAPACITY] bytes.
+    ///
+    /// [TruncatedStr::CAPACITY]: struct.TruncatedStr.html#associatedconstant.CAPACITY
+    pub fn abbrev(&self) -> &str {
+        &self.abbrev
+    }
+}
+
+impl<T> fmt::Debug for AmbiguousAbbrevError<T>
+where
+    T: Sternum,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "AmbiguousAbbrevError<{}>", <T as Sternum>::type_name())?;
+
+        f.debug_struct("")
+            .field("abbrev", &self.abbrev)
+            .field("candidates", &self.candidates)
+            .finish()
+    }
+}
+
+impl<T> fmt::Display for AmbiguousAbbrevError<T>
+where
+    T: Sternum,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Could not parse `{}' as type {}: ambiguous abbreviation of ",
+            self.abbrev,
+            <T as Sternum>::type_name()
+        )?;
+
+        for (i, name) in self.candidates.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+
+            write!(f, "`{}'", name)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> std::error::Error for AmbiguousAbbrevError<T> where T: Sternum {}
+
+/// A string that has been truncated to fit in a fixed-size buffer.
+///
+/// This is used in place of `String` to keep the string that could not be parsed in an
+/// [`UnknownVariantError`][UnknownVariantError] when the `alloc` feature is disabled.
+///
+/// [UnknownVariantError]: struct.UnknownVariantError.html
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct TruncatedStr {
+    buf: [u8; TruncatedStr::CAPACITY],
+
+    // The length fits in a byte, which keeps errors that hold a `TruncatedStr` small.
+    len: u8,
+    truncated: bool,
+}
+
+impl TruncatedStr {
+    /// The maximum length of the string, in bytes.
+    pub const CAPACITY: usize = 64;
+
+    /// The string, which is cut at a character boundary if it did not fit.
+    pub fn as_str(&self) -> &str {
+        str::from_utf8(&self.buf[..usize::from(self.len)]).expect("TruncatedStr holds valid UTF-8")
+    }
+
+    /// Whether or not the string was cut short.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+}
+
+impl<'a> From<&'a str> for TruncatedStr {
+    fn from(s: &'a str) -> Self {
+        let mut len = cmp::min(s.len(), TruncatedStr::CAPACITY);
+        while !s.is_char_boundary(len) {
+            len -= 1;
+        }
+
+        let mut buf = [0; TruncatedStr::CAPACITY];
+        buf[..len].copy_from_slice(&s.as_bytes()[..len]);
+
+        TruncatedStr {
+            buf,
+            len: len as u8,
+            truncated: len < s.len(),
+        }
+    }
+}
+
+impl Deref for TruncatedStr {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq<str> for TruncatedStr {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<'a> PartialEq<&'a str> for TruncatedStr {
+    fn eq(&self, other: &&'a str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl fmt::Debug for TruncatedStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for TruncatedStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())?;
+
+        if self.truncated {
+            f.write_str("...")?;
+        }
+
+        Ok(())
     }
 }
 
-impl<T> error::Error for UnknownVariantError<T> where T: Sternum {}
+/// The Levenshtein distance between two strings, ignoring case.
+#[cfg(feature = "alloc")]
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+
+    // The distances from the prefix of `a` seen so far to each prefix of `b`.
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = if a_char.to_lowercase().eq(b_char.to_lowercase()) {
+                diagonal
+            } else {
+                diagonal + 1
+            };
+
+            diagonal = row[j + 1];
+            row[j + 1] = cmp::min(substitution, cmp::min(row[j], row[j + 1]) + 1);
+        }
+    }
+
+    row[b.len()]
+}
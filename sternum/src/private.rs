@@ -0,0 +1,748 @@
+// Copyright 2019 Barret Rennie
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Support code for the implementations generated by `#[derive(Sternum)]`.
+//!
+//! Nothing in this module is part of the public API.
+
+use core::marker::PhantomData;
+use core::ops::Range;
+use core::str::FromStr;
+
+use crate::fold::fold_case;
+use crate::{AbbrevError, AmbiguousAbbrevError, Sternum, UnknownVariantError};
+
+#[cfg(feature = "alloc")]
+pub use alloc::borrow::Cow;
+#[cfg(feature = "alloc")]
+pub use alloc::string::String;
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use core::ptr;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+#[cfg(feature = "clap")]
+pub use clap;
+
+#[cfg(feature = "serde")]
+pub use serde;
+
+/// Strip `prefix` from the start of `s`, ignoring case.
+///
+/// The `prefix` must already be case-folded.
+pub fn strip_prefix_ignore_case<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    let mut prefix = prefix.chars();
+
+    for (i, c) in s.char_indices() {
+        if prefix.as_str().is_empty() {
+            return Some(&s[i..]);
+        }
+
+        for c in fold_case(c) {
+            if prefix.next() != Some(c) {
+                return None;
+            }
+        }
+    }
+
+    if prefix.as_str().is_empty() {
+        Some("")
+    } else {
+        None
+    }
+}
+
+/// Compare `s` with `folded`, ignoring case.
+///
+/// The `folded` string must already be case-folded.
+pub fn eq_ignore_case(s: &str, folded: &str) -> bool {
+    strip_prefix_ignore_case(s, folded) == Some("")
+}
+
+/// Copy a variant of a `#[sternum(compact)]` enum, which must be `Copy` to be indexed by its
+/// discriminant.
+#[inline]
+pub const fn copied<T: Copy>(value: &T) -> T {
+    *value
+}
+
+/// Search the names of a `#[sternum(compact)]` enum for `s`, returning the index of its variant.
+///
+/// The names must be sorted by their keys. If `ignore_case` is set, the keys must be case-folded.
+pub fn search_names(names: &[(&str, usize)], s: &str, ignore_case: bool) -> Option<usize> {
+    let found = if ignore_case {
+        names.binary_search_by(|(key, _)| key.chars().cmp(s.chars().flat_map(fold_case)))
+    } else {
+        names.binary_search_by(|(key, _)| (*key).cmp(s))
+    };
+
+    found.ok().map(|i| names[i].1)
+}
+
+/// Search the names of a `#[sternum(loose)]` enum for `s`, ignoring separators and case, and
+/// return the index of its variant.
+///
+/// The names must have their separators removed, be case-folded, and be sorted.
+pub fn search_names_loose(names: &[(&str, usize)], s: &str) -> Option<usize> {
+    let normalized = || {
+        s.chars()
+            .filter(|&c| !is_loose_separator(c))
+            .flat_map(fold_case)
+    };
+
+    names
+        .binary_search_by(|(key, _)| key.chars().cmp(normalized()))
+        .ok()
+        .map(|i| names[i].1)
+}
+
+/// Whether `#[sternum(loose)]` ignores the character.
+fn is_loose_separator(c: char) -> bool {
+    matches!(c, '-' | '_' | '.') || c.is_whitespace()
+}
+
+/// The names of the unit variants of a `#[sternum(normalize = ...)]` enum, normalized the first
+/// time they are needed.
+///
+/// The normalization function is only known at run time, so names of different variants that
+/// are the same once normalized cannot be rejected at compile time. Instead, only the name of
+/// the variant declared first is kept.
+///
+/// Caching the names needs compare-and-swap on pointers. On targets without it, the names are
+/// normalized each time instead.
+#[cfg(feature = "alloc")]
+pub struct NormalizedNames {
+    #[cfg(target_has_atomic = "ptr")]
+    names: AtomicPtr<Vec<(String, usize)>>,
+}
+
+#[cfg(feature = "alloc")]
+impl NormalizedNames {
+    pub const fn new() -> Self {
+        NormalizedNames {
+            #[cfg(target_has_atomic = "ptr")]
+            names: AtomicPtr::new(ptr::null_mut()),
+        }
+    }
+
+    /// Search the `names`, normalized with `normalize`, for the normalized input `s`, and return
+    /// the index of its variant.
+    ///
+    /// The `names` must be in declaration order. If `ignore_case` is set, the names and `s` are
+    /// also case-folded.
+    pub fn search(
+        &self,
+        names: &[(&str, usize)],
+        normalize: impl Fn(&str) -> String,
+        s: &str,
+        ignore_case: bool,
+    ) -> Option<usize> {
+        let folded;
+        let s = if ignore_case {
+            folded = fold_case_str(s);
+            &folded
+        } else {
+            s
+        };
+
+        self.with(
+            names,
+            |name| {
+                let name = normalize(name);
+                if ignore_case {
+                    fold_case_str(&name)
+                } else {
+                    name
+                }
+            },
+            |names| {
+                names
+                    .binary_search_by(|(name, _)| name.as_str().cmp(s))
+                    .ok()
+                    .map(|i| names[i].1)
+            },
+        )
+    }
+
+    /// Call `f` with the normalized names, normalizing them if they are not cached yet.
+    #[cfg(target_has_atomic = "ptr")]
+    fn with<R>(
+        &self,
+        names: &[(&str, usize)],
+        normalize: impl Fn(&str) -> String,
+        f: impl FnOnce(&[(String, usize)]) -> R,
+    ) -> R {
+        let current = self.names.load(Ordering::Acquire);
+        if !current.is_null() {
+            // SAFETY: The pointer was leaked from a `Box` below and is never freed.
+            return f(unsafe { &*current });
+        }
+
+        let normalized = Box::into_raw(Box::new(normalize_names(names, normalize)));
+
+        match self.names.compare_exchange(
+            ptr::null_mut(),
+            normalized,
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        ) {
+            // SAFETY: The pointer was just leaked from a `Box` and is never freed.
+            Ok(_) => f(unsafe { &*normalized }),
+
+            // Another thread normalized the names first.
+            Err(current) => {
+                // SAFETY: The pointer was just leaked from a `Box` and never shared.
+                drop(unsafe { Box::from_raw(normalized) });
+                // SAFETY: The pointer was leaked from a `Box` above and is never freed.
+                f(unsafe { &*current })
+            }
+        }
+    }
+
+    /// Call `f` with the normalized names.
+    #[cfg(not(target_has_atomic = "ptr"))]
+    fn with<R>(
+        &self,
+        names: &[(&str, usize)],
+        normalize: impl Fn(&str) -> String,
+        f: impl FnOnce(&[(String, usize)]) -> R,
+    ) -> R {
+        f(&normalize_names(names, normalize))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Default for NormalizedNames {
+    fn default() -> Self {
+        NormalizedNames::new()
+    }
+}
+
+/// Normalize the `names`, and sort them without duplicates.
+#[cfg(feature = "alloc")]
+fn normalize_names(
+    names: &[(&str, usize)],
+    normalize: impl Fn(&str) -> String,
+) -> Vec<(String, usize)> {
+    let mut normalized: Vec<(String, usize)> = names
+        .iter()
+        .map(|&(name, i)| (normalize(name), i))
+        .collect();
+
+    // The sort is stable and `dedup_by` keeps the first of each run, so the name of the variant
+    // declared first wins.
+    normalized.sort_by(|(a, _), (b, _)| a.cmp(b));
+    normalized.dedup_by(|(a, _), (b, _)| a == b);
+
+    normalized
+}
+
+/// Fold the case of `s` for comparison with the case-folded names of a normalized enum.
+#[cfg(feature = "alloc")]
+fn fold_case_str(s: &str) -> String {
+    s.chars().flat_map(fold_case).collect()
+}
+
+/// Find the names of the unit variants of an enum that start with `prefix`, returning their
+/// indices in declaration order.
+///
+/// If `ignore_case` is set, `prefix` must end where the case folding of a character of the name
+/// ends, so that a prefix never completes to a name it only matches part of a character of.
+pub fn complete<'a>(
+    names: &'static [&'static str],
+    prefix: &'a str,
+    ignore_case: bool,
+) -> impl Iterator<Item = usize> + 'a {
+    names
+        .iter()
+        .enumerate()
+        .filter(move |(_, name)| {
+            if ignore_case {
+                starts_with_chars_ignore_case(name, prefix)
+            } else {
+                name.starts_with(prefix)
+            }
+        })
+        .map(|(i, _)| i)
+}
+
+/// Whether `name` starts with `s`, ignoring case, where `s` ends on a character of `name`.
+fn starts_with_chars_ignore_case(name: &str, s: &str) -> bool {
+    let mut prefix = s.chars().flat_map(fold_case).peekable();
+
+    for c in name.chars() {
+        if prefix.peek().is_none() {
+            return true;
+        }
+
+        for c in fold_case(c) {
+            if prefix.next() != Some(c) {
+                return false;
+            }
+        }
+    }
+
+    prefix.next().is_none()
+}
+
+/// Parse the first of `names` that `s` starts with, returning the index of its variant and the
+/// rest of `s`.
+///
+/// The names must be sorted from longest to shortest. If `ignore_case` is set, the names must be
+/// case-folded.
+pub fn parse_prefix<'a>(
+    names: &[(&str, usize)],
+    s: &'a str,
+    ignore_case: bool,
+) -> Option<(usize, &'a str)> {
+    names.iter().find_map(|&(name, i)| {
+        let rest = if ignore_case {
+            strip_prefix_ignore_case(s, name)
+        } else {
+            s.strip_prefix(name)
+        };
+
+        rest.map(|rest| (i, rest))
+    })
+}
+
+/// A state of the Aho-Corasick automaton that `find_iter` searches with.
+///
+/// This must be kept in sync with the automaton builder in `sternum_derive`.
+pub struct SearchState {
+    /// The index of the first transition of the state, and the number of transitions. The
+    /// transitions are sorted by byte.
+    pub transitions: (usize, usize),
+
+    /// The state to fall back to when the next byte has no transition.
+    pub fail: usize,
+
+    /// The length of the names that lead to this state.
+    pub depth: usize,
+
+    /// The index and length of the longest name that the input ends with in this state.
+    pub output: Option<(usize, usize)>,
+
+    /// The state whose output is the longest name that is shorter than the output of this state
+    /// and that the input also ends with, or the start state if there is none.
+    pub dict_suffix: usize,
+}
+
+/// An iterator over the non-overlapping occurrences of the names of an enum in a string,
+/// yielding the range of each occurrence and the index of the name.
+///
+/// When occurrences overlap, the one that starts first wins, and then the longest one.
+pub struct FindIter<'a> {
+    haystack: &'a str,
+    pos: usize,
+    states: &'static [SearchState],
+    transitions: &'static [(u8, usize)],
+    ignore_case: bool,
+}
+
+/// An occurrence found by `FindIter`.
+struct Found {
+    /// The start of the occurrence, in bytes fed to the automaton.
+    start: usize,
+
+    /// The range of the occurrence in the haystack.
+    range: Range<usize>,
+
+    /// The index of the name.
+    index: usize,
+}
+
+impl<'a> FindIter<'a> {
+    /// Search `haystack` with an automaton. If `ignore_case` is set, the names that the automaton
+    /// was built from must be case-folded.
+    pub fn new(
+        haystack: &'a str,
+        states: &'static [SearchState],
+        transitions: &'static [(u8, usize)],
+        ignore_case: bool,
+    ) -> Self {
+        FindIter {
+            haystack,
+            pos: 0,
+            states,
+            transitions,
+            ignore_case,
+        }
+    }
+
+    /// Take the transition on `byte` from `state`.
+    fn step(&self, mut state: usize, byte: u8) -> usize {
+        loop {
+            let (first, len) = self.states[state].transitions;
+            let transitions = &self.transitions[first..first + len];
+
+            if let Ok(i) = transitions.binary_search_by_key(&byte, |&(byte, _)| byte) {
+                return transitions[i].1;
+            }
+
+            if state == 0 {
+                return 0;
+            }
+
+            state = self.states[state].fail;
+        }
+    }
+
+    /// Find the start of an occurrence that ends at `end` and whose case-folded form is `len`
+    /// bytes long, if it starts on a character boundary.
+    fn start_ignore_case(&self, end: usize, len: usize) -> Option<usize> {
+        let mut folded_len = 0;
+
+        for (i, c) in self.haystack[self.pos..end].char_indices().rev() {
+            folded_len += fold_case(c).map(char::len_utf8).sum::<usize>();
+
+            if folded_len >= len {
+                return if folded_len == len {
+                    Some(self.pos + i)
+                } else {
+                    None
+                };
+            }
+        }
+
+        None
+    }
+
+    /// Update the occurrence found so far after the automaton reaches `state` at `end` in the
+    /// haystack, having been fed `fed` bytes.
+    ///
+    /// This returns `false` when no better occurrence can be found.
+    fn visit(&self, state: usize, fed: usize, end: usize, found: &mut Option<Found>) -> bool {
+        let mut state = &self.states[state];
+
+        // Once the automaton no longer reaches back to the start of the occurrence, no
+        // occurrence that starts at or before it remains.
+        if let Some(Found { start, .. }) = *found {
+            if fed - state.depth > start {
+                return false;
+            }
+        }
+
+        // When case-folded, the longest name may start inside a character that folds to several,
+        // so the shorter names that end here are tried in turn.
+        let mut output = state.output;
+
+        while let Some((index, len)) = output {
+            let start = fed - len;
+
+            let better = match found {
+                Some(found) => start <= found.start,
+                None => true,
+            };
+
+            if !better {
+                break;
+            }
+
+            let range_start = if self.ignore_case {
+                self.start_ignore_case(end, len)
+            } else {
+                Some(end - len)
+            };
+
+            if let Some(range_start) = range_start {
+                *found = Some(Found {
+                    start,
+                    range: range_start..end,
+                    index,
+                });
+                break;
+            }
+
+            state = &self.states[state.dict_suffix];
+            output = state.output;
+        }
+
+        true
+    }
+}
+
+impl Iterator for FindIter<'_> {
+    type Item = (Range<usize>, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut state = 0;
+
+        // The number of bytes that have been fed to the automaton, which differs from the number
+        // of bytes of the haystack that have been searched when the bytes are case-folded.
+        let mut fed = 0;
+
+        // The earliest and then longest occurrence so far.
+        let mut found = None;
+
+        if self.ignore_case {
+            let mut buf = [0; 4];
+
+            for (i, c) in self.haystack[self.pos..].char_indices() {
+                for c in fold_case(c) {
+                    for &byte in c.encode_utf8(&mut buf).as_bytes() {
+                        state = self.step(state, byte);
+                        fed += 1;
+                    }
+                }
+
+                let end = self.pos + i + c.len_utf8();
+                if !self.visit(state, fed, end, &mut found) {
+                    break;
+                }
+            }
+        } else {
+            // Names are whole characters, so they can only be found on character boundaries.
+            for (i, &byte) in self.haystack.as_bytes()[self.pos..].iter().enumerate() {
+                state = self.step(state, byte);
+                fed += 1;
+
+                if !self.visit(state, fed, self.pos + i + 1, &mut found) {
+                    break;
+                }
+            }
+        }
+
+        // The search may have read past the end of the occurrence while looking for a longer one,
+        // so the next search reads those bytes again.
+        match found {
+            Some(Found { range, index, .. }) => {
+                self.pos = range.end;
+                Some((range, index))
+            }
+            None => {
+                self.pos = self.haystack.len();
+                None
+            }
+        }
+    }
+}
+
+/// Parse `s` as a variant of a `#[sternum(abbrev)]` enum, or as an abbreviation of the name of
+/// one of its unit variants.
+///
+/// The `keys` are the (case-folded, if `ignore_case` is set) names of the unit variants, sorted,
+/// and `names` are the names themselves in the same order. If `trim` is set, the abbreviation is
+/// trimmed, but errors keep `s` as it was given.
+pub fn parse_abbrev<T>(
+    s: &str,
+    keys: &[&str],
+    names: &'static [&'static str],
+    ignore_case: bool,
+    trim: bool,
+) -> Result<T, AbbrevError<T>>
+where
+    T: Sternum + FromStr<Err = UnknownVariantError<T>>,
+{
+    let err = match T::from_str(s) {
+        Ok(value) => return Ok(value),
+        Err(err) => err,
+    };
+
+    let abbrev = if trim { s.trim() } else { s };
+
+    if abbrev.is_empty() {
+        return Err(AbbrevError::Unknown(err));
+    }
+
+    let starts_with = |key: &str| {
+        if ignore_case {
+            starts_with_ignore_case(key, abbrev)
+        } else {
+            key.starts_with(abbrev)
+        }
+    };
+
+    // The keys that start with `s` are all together, starting at the first key that is not less
+    // than `s`.
+    let start = if ignore_case {
+        keys.partition_point(|key| key.chars().lt(abbrev.chars().flat_map(fold_case)))
+    } else {
+        keys.partition_point(|key| *key < abbrev)
+    };
+    let len = keys[start..]
+        .iter()
+        .take_while(|key| starts_with(key))
+        .count();
+
+    match &names[start..start + len] {
+        [] => Err(AbbrevError::Unknown(err)),
+        [name] => T::from_str(name).map_err(AbbrevError::Unknown),
+        candidates => Err(AbbrevError::Ambiguous(AmbiguousAbbrevError::new(
+            s, candidates,
+        ))),
+    }
+}
+
+/// Whether `folded`, which must already be case-folded, starts with `s`, ignoring case.
+fn starts_with_ignore_case(folded: &str, s: &str) -> bool {
+    let mut rest = folded;
+
+    for c in s.chars().flat_map(fold_case) {
+        match rest.strip_prefix(c) {
+            Some(next) => rest = next,
+            None => return false,
+        }
+    }
+
+    true
+}
+
+/// Find the slot of the perfect hash table of a `#[sternum(dispatch = "phf")]` enum that `key`
+/// may be in. The number of slots is a power of two, and `mask` is one less than it.
+///
+/// This must be kept in sync with the hash table builder in `sternum_derive`.
+#[inline]
+pub fn phf_slot(
+    key: &[u8],
+    seed: u64,
+    ignore_ascii_case: bool,
+    displacements: &[(u32, u32)],
+    mask: u32,
+) -> u32 {
+    let (g, f1, f2) = phf_hash(key, seed, ignore_ascii_case);
+    let (d1, d2) = displacements[reduce(g, displacements.len() as u32) as usize];
+
+    d2.wrapping_add(f1.wrapping_mul(d1)).wrapping_add(f2) & mask
+}
+
+#[inline]
+fn phf_hash(key: &[u8], seed: u64, ignore_ascii_case: bool) -> (u32, u32, u32) {
+    let mut hash = seed ^ (key.len() as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+
+    // The key is hashed eight bytes at a time, with the last word padded with zeroes.
+    let mut chunks = key.chunks_exact(8);
+    let rest = chunks.remainder();
+
+    for chunk in chunks.by_ref() {
+        let mut word = [0; 8];
+        word.copy_from_slice(chunk);
+        hash = hash_word(hash, u64::from_le_bytes(word), ignore_ascii_case);
+    }
+
+    if !rest.is_empty() {
+        let word = rest
+            .iter()
+            .rev()
+            .fold(0, |word, &byte| (word << 8) | u64::from(byte));
+        hash = hash_word(hash, word, ignore_ascii_case);
+    }
+
+    let a = mix(hash);
+    let b = mix(a);
+
+    ((a >> 32) as u32, a as u32, b as u32)
+}
+
+#[inline]
+fn hash_word(hash: u64, word: u64, ignore_ascii_case: bool) -> u64 {
+    let word = if ignore_ascii_case {
+        word_to_ascii_lowercase(word)
+    } else {
+        word
+    };
+
+    (hash.rotate_left(5) ^ word).wrapping_mul(0x517c_c1b7_2722_0a95)
+}
+
+/// Lowercase each byte of a word, which must only contain ASCII bytes.
+#[inline]
+fn word_to_ascii_lowercase(word: u64) -> u64 {
+    const LSB: u64 = 0x0101_0101_0101_0101;
+
+    // The high bit of each byte is set when the byte is at least `A' or greater than `Z',
+    // respectively.
+    let ge_a = word + LSB * (0x80 - u64::from(b'A'));
+    let gt_z = word + LSB * (0x80 - u64::from(b'Z') - 1);
+    let upper = ge_a & !gt_z & (LSB * 0x80);
+
+    word | (upper >> 2)
+}
+
+#[inline]
+fn mix(mut z: u64) -> u64 {
+    z = z.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Map `x` into the range `0..n` without dividing.
+#[inline]
+fn reduce(x: u32, n: u32) -> u32 {
+    ((u64::from(x) * u64::from(n)) >> 32) as u32
+}
+
+/// Parse the inner value of a `#[sternum(nested)]` or `#[sternum(flatten)]` variant.
+pub fn parse_nested<T>(s: &str) -> Result<T, T::Err>
+where
+    T: Sternum + FromStr,
+{
+    T::from_str(s)
+}
+
+/// Why the rest of a string could not be parsed as the inner value of a `#[sternum(nested)]`
+/// variant, as kept in an `UnknownVariantError`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Nested {
+    /// Where the rest of the string starts in the string of the error.
+    pub start: usize,
+
+    /// The name of the innermost type that could not parse the rest of the string.
+    pub type_name: &'static str,
+
+    /// The `Sternum::NAMES` of that type.
+    pub names: &'static [&'static str],
+
+    /// The `Sternum::UNIT_ONLY` of that type.
+    pub unit_only: bool,
+}
+
+/// Describe why `rest`, which is part of the `input` of the outer enum, could not be parsed as
+/// the inner value of a `#[sternum(nested)]` variant.
+///
+/// This returns `None` if `rest` is not part of `input` because the input was normalized.
+pub fn nested_error<T>(input: &str, rest: &str, err: UnknownVariantError<T>) -> Option<Nested>
+where
+    T: Sternum,
+{
+    let offset = (rest.as_ptr() as usize).checked_sub(input.as_ptr() as usize)?;
+    if offset + rest.len() > input.len() {
+        return None;
+    }
+
+    // The error of the inner enum is for `rest`, so its nested variant starts `offset` bytes
+    // later in `input`.
+    Some(match err.nested {
+        Some(nested) => Nested {
+            start: offset + nested.start,
+            ..nested
+        },
+        None => Nested {
+            start: offset,
+            type_name: T::type_name(),
+            names: T::NAMES,
+            unit_only: T::UNIT_ONLY,
+        },
+    })
+}
+
+/// Generate the error for a string that could not be parsed, keeping why the inner value of a
+/// `#[sternum(nested)]` variant could not be parsed, if it was tried.
+pub fn unknown_variant<T>(variant: &str, nested: Option<Nested>) -> UnknownVariantError<T> {
+    UnknownVariantError {
+        variant: variant.into(),
+        nested,
+        _ty: PhantomData,
+    }
+}
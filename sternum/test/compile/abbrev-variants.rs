@@ -0,0 +1,38 @@
+// Any copyright is dedicated to the Public Domain.
+// https://creativecommons.org/publicdomain/zero/1.0/
+
+use std::borrow::Cow;
+
+use sternum::Sternum;
+
+fn identity(s: &str) -> Cow<'_, str> {
+    Cow::Borrowed(s)
+}
+
+#[derive(Sternum)]
+#[sternum(abbrev)]
+enum A {
+    A1,
+    #[sternum(other)]
+    A2(String),
+}
+
+#[derive(Sternum)]
+enum B {
+    #[sternum(abbrev)]
+    B1,
+}
+
+#[derive(Sternum)]
+#[sternum(abbrev, loose)]
+enum C {
+    C1,
+}
+
+#[derive(Sternum)]
+#[sternum(normalize = identity, abbrev)]
+enum D {
+    D1,
+}
+
+fn main() {}
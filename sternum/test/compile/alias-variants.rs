@@ -0,0 +1,34 @@
+// Any copyright is dedicated to the Public Domain.
+// https://creativecommons.org/publicdomain/zero/1.0/
+
+use sternum::Sternum;
+
+#[derive(Sternum)]
+enum A {
+    #[sternum(alias = "a")]
+    A1,
+    #[sternum(alias = "a")]
+    A2,
+}
+
+#[derive(Sternum)]
+enum B {
+    B1,
+    #[sternum(alias = "B1")]
+    B2,
+}
+
+#[derive(Sternum)]
+#[sternum(case_insensitive)]
+enum C {
+    #[sternum(alias = "c", alias = "C")]
+    C1,
+}
+
+#[derive(Sternum)]
+#[sternum(alias = "d")]
+enum D {
+    D1,
+}
+
+fn main() {}
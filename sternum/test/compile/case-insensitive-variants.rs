@@ -35,4 +35,12 @@ enum D {
     VarIaNT,
 }
 
+#[derive(Sternum)]
+#[sternum(transform = snake_case)]
+#[allow(non_camel_case_types)]
+enum E {
+    FooBar,
+    Foo_Bar,
+}
+
 fn main() {}
@@ -0,0 +1,39 @@
+// Any copyright is dedicated to the Public Domain.
+// https://creativecommons.org/publicdomain/zero/1.0/
+
+use sternum::Sternum;
+
+#[derive(Clone, Copy, Sternum)]
+#[sternum(compact)]
+enum A {
+    A1,
+    #[sternum(pattern = "A{0}")]
+    A2(u8),
+}
+
+#[derive(Sternum)]
+#[sternum(compact)]
+enum B {
+    B1,
+}
+
+#[derive(Clone, Copy, Sternum)]
+#[sternum(compact, dispatch = "trie")]
+enum C {
+    C1,
+}
+
+#[derive(Clone, Copy, Sternum)]
+enum D {
+    #[sternum(compact)]
+    D1,
+}
+
+#[derive(Clone, Copy, Sternum)]
+#[sternum(compact)]
+enum E {
+    E1,
+    E2 = 3,
+}
+
+fn main() {}
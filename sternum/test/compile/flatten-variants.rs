@@ -0,0 +1,29 @@
+// Any copyright is dedicated to the Public Domain.
+// https://creativecommons.org/publicdomain/zero/1.0/
+
+use sternum::Sternum;
+
+#[derive(Sternum)]
+enum Inner {
+    Foo,
+}
+
+#[derive(Sternum)]
+enum A {
+    #[sternum(flatten)]
+    A1,
+    #[sternum(flatten)]
+    A2 { inner: Inner },
+    #[sternum(flatten, rename = "a", alias = "b")]
+    A3(Inner),
+    #[sternum(flatten, nested)]
+    A4(Inner),
+}
+
+#[derive(Sternum)]
+#[sternum(flatten)]
+enum B {
+    B1,
+}
+
+fn main() {}
@@ -34,4 +34,28 @@ enum E {
     E1,
 }
 
+#[derive(Sternum)]
+#[sternum(transform = shouting)]
+enum F {
+    F1,
+}
+
+#[derive(Sternum)]
+#[sternum(rename_all = "shouting")]
+enum G {
+    G1,
+}
+
+#[derive(Sternum)]
+#[sternum(transform = snake_case, rename_all = "kebab-case")]
+enum H {
+    H1,
+}
+
+#[derive(Sternum)]
+#[sternum(dispatch = "hash")]
+enum I {
+    I1,
+}
+
 fn main() {}
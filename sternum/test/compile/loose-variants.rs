@@ -0,0 +1,28 @@
+// Any copyright is dedicated to the Public Domain.
+// https://creativecommons.org/publicdomain/zero/1.0/
+
+use sternum::Sternum;
+
+#[derive(Sternum)]
+#[sternum(loose)]
+enum A {
+    FooBar,
+    #[sternum(rename = "foo-bar")]
+    Other,
+    #[sternum(alias = "FOO BAR")]
+    Another,
+}
+
+#[derive(Sternum)]
+#[sternum(loose, dispatch = "trie")]
+enum B {
+    B1,
+}
+
+#[derive(Sternum)]
+enum C {
+    #[sternum(loose)]
+    C1,
+}
+
+fn main() {}
@@ -0,0 +1,37 @@
+// Any copyright is dedicated to the Public Domain.
+// https://creativecommons.org/publicdomain/zero/1.0/
+
+use sternum::Sternum;
+
+#[derive(Sternum)]
+enum Inner {
+    Foo,
+}
+
+#[derive(Sternum)]
+enum A {
+    #[sternum(nested)]
+    A1,
+    #[sternum(nested)]
+    A2(Inner, Inner),
+    #[sternum(nested, alias = "a")]
+    A3(Inner),
+    #[sternum(nested, other)]
+    A4(String),
+    #[sternum(nested, pattern = "{0}")]
+    A5(Inner),
+}
+
+#[derive(Sternum)]
+#[sternum(nested)]
+enum B {
+    B1,
+}
+
+#[derive(Sternum)]
+enum C {
+    #[sternum(separator = "/")]
+    C1,
+}
+
+fn main() {}
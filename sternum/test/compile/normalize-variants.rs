@@ -0,0 +1,36 @@
+// Any copyright is dedicated to the Public Domain.
+// https://creativecommons.org/publicdomain/zero/1.0/
+
+use std::borrow::Cow;
+
+use sternum::Sternum;
+
+fn identity(s: &str) -> Cow<'_, str> {
+    Cow::Borrowed(s)
+}
+
+#[derive(Sternum)]
+#[sternum(normalize = identity, loose)]
+enum A {
+    A1,
+}
+
+#[derive(Sternum)]
+#[sternum(dispatch = "phf", normalize = identity)]
+enum B {
+    B1,
+}
+
+#[derive(Sternum)]
+enum C {
+    #[sternum(normalize = identity)]
+    C1,
+}
+
+#[derive(Sternum)]
+#[sternum(normalize = identity, normalize = str::trim)]
+enum D {
+    D1,
+}
+
+fn main() {}
@@ -0,0 +1,36 @@
+// Any copyright is dedicated to the Public Domain.
+// https://creativecommons.org/publicdomain/zero/1.0/
+
+use sternum::Sternum;
+
+#[derive(Sternum)]
+enum A {
+    #[sternum(other)]
+    A1(String),
+    #[sternum(other)]
+    A2(String),
+}
+
+#[derive(Sternum)]
+enum B {
+    #[sternum(other)]
+    B1,
+    #[sternum(other)]
+    B2(String, String),
+    #[sternum(other)]
+    B3 { value: String },
+}
+
+#[derive(Sternum)]
+enum C {
+    #[sternum(other, rename = "c", alias = "d")]
+    C1(String),
+}
+
+#[derive(Sternum)]
+#[sternum(other)]
+enum D {
+    D1(String),
+}
+
+fn main() {}
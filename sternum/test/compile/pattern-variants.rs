@@ -0,0 +1,34 @@
+// Any copyright is dedicated to the Public Domain.
+// https://creativecommons.org/publicdomain/zero/1.0/
+
+use sternum::Sternum;
+
+#[derive(Sternum)]
+enum A {
+    #[sternum(pattern = "a")]
+    A1,
+    #[sternum(pattern = "{1}")]
+    A2(u32),
+    #[sternum(pattern = "{x}")]
+    A3 { y: u32 },
+    #[sternum(pattern = "{0}")]
+    A4(u32, u32),
+    #[sternum(pattern = "{0}{1}")]
+    A5(u32, u32),
+    #[sternum(pattern = "{0}-{0}")]
+    A6(u32),
+    #[sternum(pattern = "{0")]
+    A7(u32),
+    #[sternum(pattern = "}{0}")]
+    A8(u32),
+}
+
+#[derive(Sternum)]
+enum B {
+    #[sternum(pattern = "b{0}", rename = "b", alias = "c")]
+    B1(u32),
+    #[sternum(other, pattern = "{0}")]
+    B2(String),
+}
+
+fn main() {}
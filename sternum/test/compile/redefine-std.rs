@@ -6,6 +6,19 @@ use sternum::Sternum;
 // Redefine std::fmt::{Display, Result, Formatter} and std::str::FromStr (and
 // variants) to ensure the derive implementation uses fully-scoped names.
 
+mod core {
+    mod fmt {
+        struct Display;
+        type Result = ();
+
+        trait Formatter {}
+    }
+
+    mod str {
+        struct FromStr;
+    }
+}
+
 mod std {
     mod fmt {
         struct Display;
@@ -0,0 +1,40 @@
+// Any copyright is dedicated to the Public Domain.
+// https://creativecommons.org/publicdomain/zero/1.0/
+
+use sternum::Sternum;
+
+#[derive(Sternum)]
+enum A {
+    Foo,
+    #[sternum(rename = "Foo")]
+    Bar,
+}
+
+#[derive(Sternum)]
+#[sternum(case_insensitive)]
+enum B {
+    #[sternum(rename = "foo")]
+    Foo,
+    #[sternum(rename = "FOO")]
+    Bar,
+}
+
+#[derive(Sternum)]
+#[sternum(rename = "c")]
+enum C {
+    C1,
+}
+
+#[derive(Sternum)]
+enum D {
+    #[sternum(scoped)]
+    D1,
+}
+
+#[derive(Sternum)]
+enum E {
+    #[sternum(rename = "e1", rename = "e2")]
+    E1,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+// Any copyright is dedicated to the Public Domain.
+// https://creativecommons.org/publicdomain/zero/1.0/
+
+use sternum::Sternum;
+
+#[derive(Sternum)]
+#[sternum(serde)]
+enum A {
+    Foo,
+}
+
+fn main() {}
@@ -2,8 +2,33 @@
 // https://creativecommons.org/publicdomain/zero/1.0/
 
 mod test_impl;
+mod test_impl_abbrev;
+mod test_impl_alias;
+mod test_impl_as_str;
+mod test_impl_case_insensitive;
+#[cfg(feature = "clap")]
+mod test_impl_clap;
+mod test_impl_compact;
+mod test_impl_complete;
+mod test_impl_dispatch;
+mod test_impl_error;
+mod test_impl_find_iter;
+mod test_impl_flatten;
+mod test_impl_loose;
+mod test_impl_nested;
+mod test_impl_normalize;
+mod test_impl_other;
+mod test_impl_parse_prefix;
+mod test_impl_pattern;
+mod test_impl_rename;
+mod test_impl_rename_all;
 mod test_impl_scoped;
+#[cfg(feature = "serde")]
+mod test_impl_serde;
+mod test_impl_sternum;
 mod test_impl_transform;
+mod test_impl_trim;
+mod test_impl_try_from;
 
 use trybuild::TestCases;
 
@@ -18,4 +43,30 @@ fn compile() {
     t.compile_fail("test/compile/require-enum-variants.rs");
     t.compile_fail("test/compile/invalid-attributes.rs");
     t.compile_fail("test/compile/case-insensitive-variants.rs");
+    t.compile_fail("test/compile/rename-variants.rs");
+    t.compile_fail("test/compile/alias-variants.rs");
+    t.compile_fail("test/compile/other-variants.rs");
+    t.compile_fail("test/compile/pattern-variants.rs");
+    t.compile_fail("test/compile/nested-variants.rs");
+    t.compile_fail("test/compile/flatten-variants.rs");
+    t.compile_fail("test/compile/compact-variants.rs");
+    t.compile_fail("test/compile/abbrev-variants.rs");
+    t.compile_fail("test/compile/loose-variants.rs");
+    t.compile_fail("test/compile/normalize-variants.rs");
+
+    #[cfg(not(feature = "clap"))]
+    t.compile_fail("test/compile/clap-feature.rs");
+
+    #[cfg(not(feature = "serde"))]
+    t.compile_fail("test/compile/serde-feature.rs");
+}
+
+#[test]
+fn fold_copies() {
+    // The derive folds the names with its own copy of the case folding table, which must match
+    // the one the generated code folds the input with.
+    assert!(
+        include_str!("../src/fold.rs") == include_str!("../../sternum_derive/src/fold.rs"),
+        "sternum/src/fold.rs and sternum_derive/src/fold.rs differ"
+    );
 }
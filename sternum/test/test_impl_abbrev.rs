@@ -0,0 +1,137 @@
+// Any copyright is dedicated to the Public Domain.
+// https://creativecommons.org/publicdomain/zero/1.0/
+
+use sternum::{AbbrevError, AmbiguousAbbrevError, Sternum, UnknownVariantError};
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+#[sternum(abbrev, rename_all = "lowercase")]
+enum Command {
+    Status,
+    Stash,
+    Commit,
+    Co,
+    #[sternum(alias = "ci")]
+    Checkout,
+    #[sternum(pattern = "log-{0}")]
+    Log(u32),
+}
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+#[sternum(abbrev, case_insensitive)]
+enum Unicode {
+    Ärger,
+    Ärmel,
+    Zebra,
+}
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+#[sternum(abbrev, trim, rename_all = "lowercase")]
+enum Trimmed {
+    Status,
+    Stash,
+}
+
+#[test]
+fn parse_abbrev() {
+    assert_eq!(Command::parse_abbrev("stat"), Ok(Command::Status));
+    assert_eq!(Command::parse_abbrev("stas"), Ok(Command::Stash));
+    assert_eq!(Command::parse_abbrev("com"), Ok(Command::Commit));
+    assert_eq!(Command::parse_abbrev("ch"), Ok(Command::Checkout));
+    assert_eq!(Command::parse_abbrev("status"), Ok(Command::Status));
+
+    // Names, aliases, and patterns take precedence over abbreviations.
+    assert_eq!(Command::parse_abbrev("co"), Ok(Command::Co));
+    assert_eq!(Command::parse_abbrev("ci"), Ok(Command::Checkout));
+    assert_eq!(Command::parse_abbrev("log-3"), Ok(Command::Log(3)));
+}
+
+#[test]
+fn parse_abbrev_ambiguous() {
+    assert_eq!(
+        Command::parse_abbrev("st"),
+        Err(AbbrevError::Ambiguous(AmbiguousAbbrevError::new(
+            "st",
+            &["stash", "status"]
+        )))
+    );
+    assert_eq!(
+        Command::parse_abbrev("c"),
+        Err(AbbrevError::Ambiguous(AmbiguousAbbrevError::new(
+            "c",
+            &["checkout", "co", "commit"]
+        )))
+    );
+
+    assert_eq!(
+        Command::parse_abbrev("st").unwrap_err().to_string(),
+        "Could not parse `st' as type Command: ambiguous abbreviation of `stash', `status'"
+    );
+
+    match Command::parse_abbrev("st") {
+        Err(AbbrevError::Ambiguous(err)) => assert_eq!(err.abbrev(), "st"),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn parse_abbrev_unknown() {
+    assert_eq!(
+        Command::parse_abbrev(""),
+        Err(AbbrevError::Unknown(UnknownVariantError::new("")))
+    );
+    assert_eq!(
+        Command::parse_abbrev("Stat"),
+        Err(AbbrevError::Unknown(UnknownVariantError::new("Stat")))
+    );
+    assert_eq!(
+        Command::parse_abbrev("statuses"),
+        Err(AbbrevError::Unknown(UnknownVariantError::new("statuses")))
+    );
+    assert_eq!(
+        Command::parse_abbrev("log"),
+        Err(AbbrevError::Unknown(UnknownVariantError::new("log")))
+    );
+}
+
+#[test]
+fn parse_abbrev_case_insensitive() {
+    assert_eq!(Unicode::parse_abbrev("ÄRG"), Ok(Unicode::Ärger));
+    assert_eq!(Unicode::parse_abbrev("ärm"), Ok(Unicode::Ärmel));
+    assert_eq!(Unicode::parse_abbrev("Z"), Ok(Unicode::Zebra));
+    assert_eq!(
+        Unicode::parse_abbrev("äR"),
+        Err(AbbrevError::Ambiguous(AmbiguousAbbrevError::new(
+            "äR",
+            &["Ärger", "Ärmel"]
+        )))
+    );
+}
+
+#[test]
+fn min_abbrev() {
+    assert_eq!(Command::min_abbrev(), &["stat", "stas", "com", "co", "ch"]);
+    assert_eq!(Command::min_abbrev().len(), Command::NAMES.len());
+    assert_eq!(Unicode::min_abbrev(), &["Ärg", "Ärm", "Z"]);
+
+    for (abbrev, name) in Command::min_abbrev().iter().zip(Command::NAMES) {
+        assert_eq!(Command::parse_abbrev(abbrev).unwrap().to_string(), *name);
+    }
+}
+
+#[test]
+fn parse_abbrev_trim() {
+    assert_eq!(Trimmed::parse_abbrev(" status "), Ok(Trimmed::Status));
+    assert_eq!(Trimmed::parse_abbrev(" stat "), Ok(Trimmed::Status));
+    assert_eq!(Trimmed::parse_abbrev("\tstas\n"), Ok(Trimmed::Stash));
+    assert_eq!(
+        Trimmed::parse_abbrev(" st "),
+        Err(AbbrevError::Ambiguous(AmbiguousAbbrevError::new(
+            " st ",
+            &["stash", "status"]
+        )))
+    );
+    assert_eq!(
+        Trimmed::parse_abbrev("  "),
+        Err(AbbrevError::Unknown(UnknownVariantError::new("  ")))
+    );
+}
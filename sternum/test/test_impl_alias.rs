@@ -0,0 +1,61 @@
+// Any copyright is dedicated to the Public Domain.
+// https://creativecommons.org/publicdomain/zero/1.0/
+
+use sternum::{Sternum, UnknownVariantError};
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+enum Enum {
+    #[sternum(alias = "old_foo", alias = "older_foo")]
+    Foo,
+    #[sternum(rename = "bar", alias = "Bar")]
+    Bar,
+    Baz,
+}
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+#[sternum(case_insensitive)]
+enum CaseInsensitiveEnum {
+    #[sternum(alias = "Legacy")]
+    Foo,
+}
+
+#[test]
+fn impl_display() {
+    assert_eq!(Enum::Foo.to_string(), "Foo");
+    assert_eq!(Enum::Bar.to_string(), "bar");
+    assert_eq!(Enum::Baz.to_string(), "Baz");
+
+    assert_eq!(CaseInsensitiveEnum::Foo.to_string(), "Foo");
+}
+
+#[test]
+fn impl_from_str() {
+    assert_eq!(str::parse::<Enum>("Foo"), Ok(Enum::Foo));
+    assert_eq!(str::parse::<Enum>("old_foo"), Ok(Enum::Foo));
+    assert_eq!(str::parse::<Enum>("older_foo"), Ok(Enum::Foo));
+    assert_eq!(str::parse::<Enum>("bar"), Ok(Enum::Bar));
+    assert_eq!(str::parse::<Enum>("Bar"), Ok(Enum::Bar));
+    assert_eq!(str::parse::<Enum>("Baz"), Ok(Enum::Baz));
+
+    assert_eq!(
+        str::parse::<Enum>("OLD_FOO"),
+        Err(UnknownVariantError::new("OLD_FOO"))
+    );
+
+    assert_eq!(
+        str::parse::<CaseInsensitiveEnum>("legacy"),
+        Ok(CaseInsensitiveEnum::Foo)
+    );
+    assert_eq!(
+        str::parse::<CaseInsensitiveEnum>("LEGACY"),
+        Ok(CaseInsensitiveEnum::Foo)
+    );
+}
+
+#[test]
+#[allow(clippy::unnecessary_to_owned)]
+fn round_trip() {
+    assert_eq!(str::parse::<Enum>(&Enum::Foo.to_string()), Ok(Enum::Foo));
+    assert_eq!(str::parse::<Enum>(&Enum::Bar.to_string()), Ok(Enum::Bar));
+    assert_eq!(str::parse::<Enum>(&Enum::Baz.to_string()), Ok(Enum::Baz));
+}
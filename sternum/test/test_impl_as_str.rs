@@ -0,0 +1,48 @@
+// Any copyright is dedicated to the Public Domain.
+// https://creativecommons.org/publicdomain/zero/1.0/
+
+use std::collections::HashMap;
+
+use sternum::Sternum;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Sternum)]
+#[sternum(rename_all = "snake_case")]
+enum Enum {
+    LoudNoises,
+    #[sternum(rename = "quiet!", alias = "hush")]
+    Quiet,
+}
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+#[sternum(scoped)]
+enum ScopedEnum {
+    Foo,
+}
+
+#[test]
+fn as_str() {
+    assert_eq!(Enum::LoudNoises.as_str(), "loud_noises");
+    assert_eq!(Enum::Quiet.as_str(), "quiet!");
+    assert_eq!(ScopedEnum::Foo.as_str(), "ScopedEnum::Foo");
+
+    const NAME: &str = Enum::Quiet.as_str();
+    assert_eq!(NAME, "quiet!");
+
+    for variant in Enum::VARIANTS {
+        assert_eq!(variant.as_str(), variant.to_string());
+    }
+}
+
+#[test]
+fn conversions() {
+    assert_eq!(Enum::LoudNoises.as_ref(), "loud_noises");
+    assert_eq!(<&'static str>::from(Enum::Quiet), "quiet!");
+    assert_eq!(String::from(Enum::Quiet), "quiet!");
+
+    let s: &'static str = ScopedEnum::Foo.into();
+    assert_eq!(s, "ScopedEnum::Foo");
+
+    let mut map = HashMap::new();
+    map.insert(Enum::LoudNoises.as_str(), 1);
+    assert_eq!(map.get("loud_noises"), Some(&1));
+}
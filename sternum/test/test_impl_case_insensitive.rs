@@ -0,0 +1,90 @@
+// Any copyright is dedicated to the Public Domain.
+// https://creativecommons.org/publicdomain/zero/1.0/
+
+use sternum::{Sternum, UnknownVariantError};
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+#[sternum(case_insensitive)]
+enum Level {
+    Info,
+    Warn,
+    Error,
+    #[sternum(alias = "dbg")]
+    Debug,
+}
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+#[sternum(case_insensitive)]
+enum Unicode {
+    #[sternum(rename = "Ärger")]
+    Anger,
+    #[sternum(rename = "ΣΟΦΙΑ")]
+    Wisdom,
+    Kelvin,
+    #[sternum(rename = "ΟΔΟΣ")]
+    Road,
+    #[sternum(rename = "Straße")]
+    Street,
+}
+
+#[test]
+fn impl_from_str_ascii() {
+    assert_eq!(str::parse::<Level>("info"), Ok(Level::Info));
+    assert_eq!(str::parse::<Level>("INFO"), Ok(Level::Info));
+    assert_eq!(str::parse::<Level>("wArN"), Ok(Level::Warn));
+    assert_eq!(str::parse::<Level>("Error"), Ok(Level::Error));
+    assert_eq!(str::parse::<Level>("DBG"), Ok(Level::Debug));
+
+    assert_eq!(
+        str::parse::<Level>("infos"),
+        Err(UnknownVariantError::new("infos")),
+    );
+    assert_eq!(
+        str::parse::<Level>("inf"),
+        Err(UnknownVariantError::new("inf")),
+    );
+}
+
+#[test]
+fn impl_from_str_unicode() {
+    assert_eq!(str::parse::<Unicode>("ärger"), Ok(Unicode::Anger));
+    assert_eq!(str::parse::<Unicode>("ÄRGER"), Ok(Unicode::Anger));
+    assert_eq!(str::parse::<Unicode>("σοφια"), Ok(Unicode::Wisdom));
+
+    // The Kelvin sign lowercases to an ASCII `k'.
+    assert_eq!(str::parse::<Unicode>("\u{212A}elvin"), Ok(Unicode::Kelvin));
+
+    assert_eq!(
+        str::parse::<Unicode>("arger"),
+        Err(UnknownVariantError::new("arger")),
+    );
+    assert_eq!(
+        str::parse::<Unicode>("ärgerlich"),
+        Err(UnknownVariantError::new("ärgerlich")),
+    );
+}
+
+#[test]
+fn impl_from_str_case_folding() {
+    // A final sigma folds to the same sigma as a capital sigma.
+    assert_eq!(str::parse::<Unicode>("οδος"), Ok(Unicode::Road));
+    assert_eq!(str::parse::<Unicode>("ΟΔΟΣ"), Ok(Unicode::Road));
+    assert_eq!(str::parse::<Unicode>("οδοσ"), Ok(Unicode::Road));
+
+    // A sharp s folds to `ss', so the name also matches ASCII input.
+    assert_eq!(str::parse::<Unicode>("STRASSE"), Ok(Unicode::Street));
+    assert_eq!(str::parse::<Unicode>("strasse"), Ok(Unicode::Street));
+    assert_eq!(str::parse::<Unicode>("straße"), Ok(Unicode::Street));
+    assert_eq!(str::parse::<Unicode>("STRAẞE"), Ok(Unicode::Street));
+
+    assert_eq!(
+        str::parse::<Unicode>("STRASE"),
+        Err(UnknownVariantError::new("STRASE")),
+    );
+}
+
+#[test]
+fn impl_display() {
+    assert_eq!(Unicode::Anger.to_string(), "Ärger");
+    assert_eq!(Level::Debug.to_string(), "Debug");
+}
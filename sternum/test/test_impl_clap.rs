@@ -0,0 +1,91 @@
+// Any copyright is dedicated to the Public Domain.
+// https://creativecommons.org/publicdomain/zero/1.0/
+
+use clap::builder::{EnumValueParser, PossibleValue};
+use clap::{Arg, Command, ValueEnum};
+use sternum::Sternum;
+
+#[derive(Clone, Debug, Eq, PartialEq, Sternum)]
+#[sternum(clap, rename_all = "kebab-case")]
+enum Enum {
+    /// Make some noise.
+    ///
+    /// This paragraph is not part of the help.
+    LoudNoises,
+
+    /// Keep it
+    /// down.
+    #[sternum(alias = "hush", alias = "shh")]
+    Quiet,
+
+    Silent,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Sternum)]
+#[sternum(clap)]
+enum Setting {
+    Auto,
+    #[sternum(pattern = "L{0}")]
+    Level(u8),
+}
+
+fn names(value: &PossibleValue) -> Vec<&str> {
+    value.get_name_and_aliases().collect()
+}
+
+#[test]
+fn value_variants() {
+    assert_eq!(
+        Enum::value_variants(),
+        &[Enum::LoudNoises, Enum::Quiet, Enum::Silent]
+    );
+    assert_eq!(Setting::value_variants(), &[Setting::Auto]);
+}
+
+#[test]
+fn to_possible_value() {
+    let value = Enum::LoudNoises.to_possible_value().unwrap();
+    assert_eq!(names(&value), &["loud-noises"]);
+    assert_eq!(
+        value.get_help().map(ToString::to_string),
+        Some("Make some noise.".into())
+    );
+
+    let value = Enum::Quiet.to_possible_value().unwrap();
+    assert_eq!(names(&value), &["quiet", "hush", "shh"]);
+    assert_eq!(
+        value.get_help().map(ToString::to_string),
+        Some("Keep it down.".into())
+    );
+
+    let value = Enum::Silent.to_possible_value().unwrap();
+    assert_eq!(names(&value), &["silent"]);
+    assert_eq!(value.get_help(), None);
+
+    assert!(Setting::Level(3).to_possible_value().is_none());
+}
+
+#[test]
+fn parse_args() {
+    let command = Command::new("test").arg(
+        Arg::new("enum")
+            .long("enum")
+            .value_parser(EnumValueParser::<Enum>::new()),
+    );
+
+    let matches = command
+        .clone()
+        .try_get_matches_from(["test", "--enum", "loud-noises"])
+        .unwrap();
+    assert_eq!(matches.get_one::<Enum>("enum"), Some(&Enum::LoudNoises));
+
+    let matches = command
+        .clone()
+        .try_get_matches_from(["test", "--enum", "hush"])
+        .unwrap();
+    assert_eq!(matches.get_one::<Enum>("enum"), Some(&Enum::Quiet));
+
+    assert!(command
+        .try_get_matches_from(["test", "--enum", "LoudNoises"])
+        .is_err());
+}
@@ -0,0 +1,105 @@
+// Any copyright is dedicated to the Public Domain.
+// https://creativecommons.org/publicdomain/zero/1.0/
+
+use sternum::{Sternum, UnknownVariantError};
+
+macro_rules! enums {
+    ($($name:ident: $($attr:meta),*;)*) => {
+        $(
+            #[derive(Clone, Copy, Debug, Eq, PartialEq, Sternum)]
+            #[sternum($($attr),*)]
+            enum $name {
+                Zebra,
+                #[sternum(alias = "aardvark")]
+                LoudNoises,
+                #[sternum(rename = "Ärger")]
+                Anger,
+                Middle,
+            }
+        )*
+    };
+}
+
+enums! {
+    Arms: rename_all = "kebab-case";
+    Compact: compact, rename_all = "kebab-case";
+    ArmsIgnoreCase: case_insensitive, rename_all = "SCREAMING_SNAKE_CASE";
+    CompactIgnoreCase: compact, case_insensitive, rename_all = "SCREAMING_SNAKE_CASE";
+}
+
+const INPUTS: &[&str] = &[
+    "zebra",
+    "Zebra",
+    "loud-noises",
+    "LOUD-NOISES",
+    "aardvark",
+    "AARDVARK",
+    "Ärger",
+    "ärger",
+    "middle",
+    "Middle",
+    "ÄRGER",
+    "loud_noises",
+    "LOUD_NOISES",
+    "Loud_Noises",
+    "",
+    "unknown",
+    "midd",
+    "middles",
+];
+
+#[test]
+fn impl_display() {
+    assert_eq!(Compact::LoudNoises.to_string(), "loud-noises");
+    assert_eq!(Compact::Anger.to_string(), "Ärger");
+    assert_eq!(CompactIgnoreCase::LoudNoises.to_string(), "LOUD_NOISES");
+
+    for (compact, arms) in Compact::VARIANTS.iter().zip(Arms::VARIANTS) {
+        assert_eq!(compact.to_string(), arms.to_string());
+        assert_eq!(compact.as_str(), arms.as_str());
+    }
+}
+
+#[test]
+fn impl_from_str() {
+    assert_eq!(str::parse::<Compact>("zebra"), Ok(Compact::Zebra));
+    assert_eq!(str::parse::<Compact>("aardvark"), Ok(Compact::LoudNoises));
+    assert_eq!(str::parse::<Compact>("Ärger"), Ok(Compact::Anger));
+    assert_eq!(
+        str::parse::<Compact>("Zebra"),
+        Err(UnknownVariantError::new("Zebra"))
+    );
+
+    assert_eq!(
+        str::parse::<CompactIgnoreCase>("ärger"),
+        Ok(CompactIgnoreCase::Anger)
+    );
+    assert_eq!(
+        str::parse::<CompactIgnoreCase>("Loud_Noises"),
+        Ok(CompactIgnoreCase::LoudNoises)
+    );
+    assert_eq!(
+        str::parse::<CompactIgnoreCase>("loud-noises"),
+        Err(UnknownVariantError::new("loud-noises"))
+    );
+}
+
+#[test]
+fn same_as_arms() {
+    for input in INPUTS {
+        assert_eq!(
+            str::parse::<Compact>(input).map(|v| v as usize).ok(),
+            str::parse::<Arms>(input).map(|v| v as usize).ok(),
+            "{}",
+            input,
+        );
+        assert_eq!(
+            str::parse::<CompactIgnoreCase>(input)
+                .map(|v| v as usize)
+                .ok(),
+            str::parse::<ArmsIgnoreCase>(input).map(|v| v as usize).ok(),
+            "{}",
+            input,
+        );
+    }
+}
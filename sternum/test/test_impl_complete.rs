@@ -0,0 +1,94 @@
+// Any copyright is dedicated to the Public Domain.
+// https://creativecommons.org/publicdomain/zero/1.0/
+
+use sternum::Sternum;
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+#[sternum(rename_all = "kebab-case")]
+enum Command {
+    Status,
+    Commit,
+    #[sternum(alias = "sh")]
+    Stash,
+    #[sternum(rename = "Ärger")]
+    Anger,
+    #[sternum(pattern = "stage-{0}")]
+    Stage(u8),
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Sternum)]
+#[sternum(case_insensitive)]
+enum Unicode {
+    Ärger,
+    Ärmel,
+    Zebra,
+}
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+#[sternum(case_insensitive)]
+enum Dotted {
+    İx,
+    Ix,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Sternum)]
+#[sternum(compact, case_insensitive)]
+enum Compact {
+    Ärger,
+    Ärmel,
+    Zebra,
+}
+
+#[test]
+fn complete() {
+    assert_eq!(
+        Command::complete("st").collect::<Vec<_>>(),
+        &[(Command::Status, "status"), (Command::Stash, "stash")]
+    );
+    assert_eq!(
+        Command::complete("commit").collect::<Vec<_>>(),
+        &[(Command::Commit, "commit")]
+    );
+    assert_eq!(
+        Command::complete("Ä").collect::<Vec<_>>(),
+        &[(Command::Anger, "Ärger")]
+    );
+    assert_eq!(Command::complete("").count(), Command::NAMES.len());
+
+    assert_eq!(Command::complete("St").next(), None);
+    assert_eq!(Command::complete("sh").next(), None);
+    assert_eq!(Command::complete("statuses").next(), None);
+    assert_eq!(Command::complete("stage").next(), None);
+}
+
+#[test]
+fn complete_case_insensitive() {
+    assert_eq!(
+        Unicode::complete("äR").collect::<Vec<_>>(),
+        &[(Unicode::Ärger, "Ärger"), (Unicode::Ärmel, "Ärmel")]
+    );
+    assert_eq!(
+        Unicode::complete("ZEB").collect::<Vec<_>>(),
+        &[(Unicode::Zebra, "Zebra")]
+    );
+    assert_eq!(
+        Compact::complete("ärm").collect::<Vec<_>>(),
+        &[(Compact::Ärmel, "Ärmel")]
+    );
+}
+
+#[test]
+fn complete_case_folding_boundary() {
+    assert_eq!(
+        Dotted::complete("I").collect::<Vec<_>>(),
+        &[(Dotted::Ix, "Ix")]
+    );
+    assert_eq!(
+        Dotted::complete("İ").collect::<Vec<_>>(),
+        &[(Dotted::İx, "İx")]
+    );
+    assert_eq!(
+        Dotted::complete("i\u{307}").collect::<Vec<_>>(),
+        &[(Dotted::İx, "İx")]
+    );
+}
@@ -0,0 +1,96 @@
+// Any copyright is dedicated to the Public Domain.
+// https://creativecommons.org/publicdomain/zero/1.0/
+
+use sternum::{Sternum, UnknownVariantError};
+
+macro_rules! dispatch_enums {
+    ($($name:ident: $($attr:meta),*;)*) => {
+        $(
+            #[derive(Debug, Eq, PartialEq, Sternum)]
+            #[sternum($($attr),*)]
+            enum $name {
+                Ge,
+                Gt,
+                G,
+                #[sternum(alias = "gte")]
+                GreaterOrEqual,
+                #[sternum(rename = "Ärger")]
+                Anger,
+                #[sternum(rename = "")]
+                Empty,
+                #[sternum(pattern = "L{0}")]
+                Level(u8),
+            }
+        )*
+    };
+}
+
+dispatch_enums! {
+    Match: dispatch = "match";
+    Trie: dispatch = "trie";
+    Phf: dispatch = "phf";
+    MatchIgnoreCase: dispatch = "match", case_insensitive;
+    TrieIgnoreCase: dispatch = "trie", case_insensitive;
+    PhfIgnoreCase: dispatch = "phf", case_insensitive;
+}
+
+macro_rules! test_case_sensitive {
+    ($name:ident) => {{
+        assert_eq!(str::parse::<$name>("Ge"), Ok($name::Ge));
+        assert_eq!(str::parse::<$name>("Gt"), Ok($name::Gt));
+        assert_eq!(str::parse::<$name>("G"), Ok($name::G));
+        assert_eq!(
+            str::parse::<$name>("GreaterOrEqual"),
+            Ok($name::GreaterOrEqual)
+        );
+        assert_eq!(str::parse::<$name>("gte"), Ok($name::GreaterOrEqual));
+        assert_eq!(str::parse::<$name>("Ärger"), Ok($name::Anger));
+        assert_eq!(str::parse::<$name>(""), Ok($name::Empty));
+        assert_eq!(str::parse::<$name>("L3"), Ok($name::Level(3)));
+
+        for s in &["ge", "GE", "Gx", "Gee", "Greater", "ärger", "L"] {
+            assert_eq!(str::parse::<$name>(s), Err(UnknownVariantError::new(s)));
+        }
+    }};
+}
+
+macro_rules! test_case_insensitive {
+    ($name:ident) => {{
+        assert_eq!(str::parse::<$name>("Ge"), Ok($name::Ge));
+        assert_eq!(str::parse::<$name>("ge"), Ok($name::Ge));
+        assert_eq!(str::parse::<$name>("GT"), Ok($name::Gt));
+        assert_eq!(str::parse::<$name>("g"), Ok($name::G));
+        assert_eq!(
+            str::parse::<$name>("greaterorequal"),
+            Ok($name::GreaterOrEqual)
+        );
+        assert_eq!(str::parse::<$name>("GTE"), Ok($name::GreaterOrEqual));
+        assert_eq!(str::parse::<$name>("ÄRGER"), Ok($name::Anger));
+        assert_eq!(str::parse::<$name>(""), Ok($name::Empty));
+        assert_eq!(str::parse::<$name>("L3"), Ok($name::Level(3)));
+
+        for s in &["Gx", "Gee", "Greater", "arger", "L"] {
+            assert_eq!(str::parse::<$name>(s), Err(UnknownVariantError::new(s)));
+        }
+    }};
+}
+
+#[test]
+fn impl_from_str() {
+    test_case_sensitive!(Match);
+    test_case_sensitive!(Trie);
+    test_case_sensitive!(Phf);
+}
+
+#[test]
+fn impl_from_str_case_insensitive() {
+    test_case_insensitive!(MatchIgnoreCase);
+    test_case_insensitive!(TrieIgnoreCase);
+    test_case_insensitive!(PhfIgnoreCase);
+}
+
+#[test]
+fn impl_display() {
+    assert_eq!(Phf::GreaterOrEqual.to_string(), "GreaterOrEqual");
+    assert_eq!(TrieIgnoreCase::Anger.to_string(), "Ärger");
+}
@@ -0,0 +1,112 @@
+// Any copyright is dedicated to the Public Domain.
+// https://creativecommons.org/publicdomain/zero/1.0/
+
+use sternum::{Sternum, UnknownVariantError};
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+#[sternum(rename_all = "kebab-case")]
+enum Enum {
+    Status,
+    Stash,
+    Commit,
+    LoudNoises,
+}
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+enum LargeEnum {
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+    H,
+    I,
+    J,
+}
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+enum DataEnum {
+    #[sternum(pattern = "L{0}")]
+    Level(u8),
+}
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+#[sternum(separator = ".")]
+enum Inner {
+    Foo,
+    Bar,
+}
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+#[sternum(scoped, separator = ".")]
+enum Outer {
+    Plain,
+    #[sternum(nested, rename = "In")]
+    Inner(Inner),
+}
+
+fn error(s: &str) -> UnknownVariantError<Enum> {
+    str::parse::<Enum>(s).unwrap_err()
+}
+
+#[test]
+fn suggestions() {
+    assert_eq!(error("stats").suggestions(), &["status"]);
+    assert_eq!(error("stasus").suggestions(), &["status", "stash"]);
+    assert_eq!(error("comit").suggestions(), &["commit"]);
+    assert_eq!(error("COMMIT").suggestions(), &["commit"]);
+    assert_eq!(error("loud_noises").suggestions(), &["loud-noises"]);
+    assert_eq!(error("loudnoise").suggestions(), &["loud-noises"]);
+
+    assert!(error("s").suggestions().is_empty());
+    assert!(error("push").suggestions().is_empty());
+    assert!(error("").suggestions().is_empty());
+}
+
+#[test]
+fn expected() {
+    assert_eq!(
+        error("push").expected(),
+        &["status", "stash", "commit", "loud-noises"]
+    );
+    assert!(str::parse::<DataEnum>("L")
+        .unwrap_err()
+        .expected()
+        .is_empty());
+}
+
+#[test]
+fn impl_display() {
+    assert_eq!(
+        error("comit").to_string(),
+        "Could not parse `comit' as type Enum: unknown variant \
+         (expected one of: `status', `stash', `commit', `loud-noises'); did you mean `commit'?"
+    );
+    assert_eq!(
+        error("stats").to_string(),
+        "Could not parse `stats' as type Enum: unknown variant \
+         (expected one of: `status', `stash', `commit', `loud-noises'); did you mean `status'?"
+    );
+    assert_eq!(
+        error("push").to_string(),
+        "Could not parse `push' as type Enum: unknown variant \
+         (expected one of: `status', `stash', `commit', `loud-noises')"
+    );
+
+    assert_eq!(
+        str::parse::<LargeEnum>("Z").unwrap_err().to_string(),
+        "Could not parse `Z' as type LargeEnum: unknown variant \
+         (expected one of: `A', `B', `C', `D', `E', `F', `G', `H', and 2 more)"
+    );
+    assert_eq!(
+        str::parse::<DataEnum>("L").unwrap_err().to_string(),
+        "Could not parse `L' as type DataEnum: unknown variant"
+    );
+    assert_eq!(
+        str::parse::<Outer>("Outer.In.Baz").unwrap_err().to_string(),
+        "Could not parse `Outer.In.Baz' as type Outer: unknown variant \
+         (expected one of: `Outer.Plain', or a value of a variant with fields)"
+    );
+}
@@ -0,0 +1,186 @@
+// Any copyright is dedicated to the Public Domain.
+// https://creativecommons.org/publicdomain/zero/1.0/
+
+use std::ops::Range;
+
+use sternum::Sternum;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Sternum)]
+#[sternum(rename_all = "lowercase", find_iter)]
+enum Word {
+    He,
+    She,
+    His,
+    Hers,
+    #[sternum(alias = "usher")]
+    Her,
+    #[sternum(pattern = "h{0}")]
+    Numbered(u8),
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Sternum)]
+#[sternum(case_insensitive, find_iter)]
+enum Unicode {
+    Ärger,
+    #[sternum(rename = "ΣΟΦΙΑ")]
+    Sophia,
+    #[sternum(rename = "\u{212A}elvin")]
+    Kelvin,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Sternum)]
+#[sternum(compact, case_insensitive, find_iter)]
+enum Compact {
+    A,
+    Ab,
+    Bc,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Sternum)]
+#[sternum(rename_all = "lowercase", case_insensitive, find_iter)]
+enum Folded {
+    Sx,
+    X,
+    Ss,
+    Sa,
+    Tx,
+}
+
+fn find<T>(found: impl Iterator<Item = (Range<usize>, T)>) -> Vec<(Range<usize>, T)> {
+    found.collect()
+}
+
+/// Find the names in `haystack` one position at a time.
+fn find_naive(haystack: &str) -> Vec<(Range<usize>, Word)> {
+    let mut found = vec![];
+    let mut pos = 0;
+
+    while pos < haystack.len() {
+        let longest = Word::VARIANTS
+            .iter()
+            .zip(Word::NAMES)
+            .filter(|(_, name)| haystack[pos..].starts_with(*name))
+            .max_by_key(|(_, name)| name.len());
+
+        match longest {
+            Some((word, name)) => {
+                found.push((pos..pos + name.len(), *word));
+                pos += name.len();
+            }
+            None => pos += 1,
+        }
+    }
+
+    found
+}
+
+/// Find the names in `haystack` one character at a time, ignoring case.
+fn find_naive_ignore_case(haystack: &str) -> Vec<(Range<usize>, Folded)> {
+    let boundaries: Vec<usize> = haystack
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain(Some(haystack.len()))
+        .collect();
+
+    let mut found = vec![];
+    let mut pos = 0;
+
+    while pos < boundaries.len() - 1 {
+        let longest = (pos + 1..boundaries.len()).rev().find_map(|end| {
+            let range = boundaries[pos]..boundaries[end];
+            let folded = haystack[range.clone()].parse::<Folded>().ok()?;
+            Some((end, range, folded))
+        });
+
+        match longest {
+            Some((end, range, folded)) => {
+                found.push((range, folded));
+                pos = end;
+            }
+            None => pos += 1,
+        }
+    }
+
+    found
+}
+
+#[test]
+fn find_iter() {
+    assert_eq!(find(Word::find_iter("ushers")), &[(1..4, Word::She)]);
+    assert_eq!(
+        find(Word::find_iter("hishe hers")),
+        &[(0..3, Word::His), (3..5, Word::He), (6..10, Word::Hers)]
+    );
+    assert_eq!(
+        find(Word::find_iter("ÄÄhe—her")),
+        &[(4..6, Word::He), (9..12, Word::Her)]
+    );
+
+    assert_eq!(find(Word::find_iter("")), &[]);
+    assert_eq!(find(Word::find_iter("HE h1 usher")), &[(7..10, Word::She)]);
+}
+
+#[test]
+fn find_iter_naive() {
+    let haystacks = [
+        "hershehishe",
+        "shhehisherhs",
+        "hhhhhhersss",
+        "sherherhishers",
+        "ahisbhersche",
+    ];
+
+    for haystack in &haystacks {
+        assert_eq!(
+            find(Word::find_iter(haystack)),
+            find_naive(haystack),
+            "{}",
+            haystack
+        );
+    }
+}
+
+#[test]
+fn find_iter_naive_case_insensitive() {
+    let haystacks = [
+        "ßx",
+        "SSX",
+        "ßßx",
+        "ßsx",
+        "xßa",
+        "\u{FB06}x",
+        "\u{FB05}sx",
+        "sßx",
+    ];
+
+    for haystack in &haystacks {
+        assert_eq!(
+            find(Folded::find_iter(haystack)),
+            find_naive_ignore_case(haystack),
+            "{}",
+            haystack
+        );
+    }
+
+    assert_eq!(find(Folded::find_iter("\u{FB06}x")), &[(3..4, Folded::X)]);
+}
+
+#[test]
+fn find_iter_case_insensitive() {
+    assert_eq!(
+        find(Unicode::find_iter("ÄRGER, ärger, σοφια")),
+        &[
+            (0..6, Unicode::Ärger),
+            (8..14, Unicode::Ärger),
+            (16..26, Unicode::Sophia)
+        ]
+    );
+    assert_eq!(
+        find(Unicode::find_iter("KELVIN")),
+        &[(0..6, Unicode::Kelvin)]
+    );
+    assert_eq!(
+        find(Compact::find_iter("xABC abc")),
+        &[(1..3, Compact::Ab), (5..7, Compact::Ab)]
+    );
+}
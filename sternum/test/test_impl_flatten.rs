@@ -0,0 +1,100 @@
+// Any copyright is dedicated to the Public Domain.
+// https://creativecommons.org/publicdomain/zero/1.0/
+
+use sternum::{Sternum, UnknownVariantError};
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+enum AEnum {
+    Foo,
+    Shared,
+}
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+#[sternum(transform = lowercase)]
+enum BEnum {
+    Bar,
+    Shared,
+}
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+enum Any {
+    #[sternum(flatten)]
+    A(AEnum),
+    #[sternum(flatten)]
+    B(BEnum),
+    Baz,
+}
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+enum Reversed {
+    #[sternum(flatten)]
+    B(BEnum),
+    #[sternum(flatten)]
+    A(AEnum),
+    #[sternum(other)]
+    Unknown(String),
+}
+
+#[test]
+fn impl_display() {
+    assert_eq!(Any::A(AEnum::Foo).to_string(), "Foo");
+    assert_eq!(Any::A(AEnum::Shared).to_string(), "Shared");
+    assert_eq!(Any::B(BEnum::Bar).to_string(), "bar");
+    assert_eq!(Any::B(BEnum::Shared).to_string(), "shared");
+    assert_eq!(Any::Baz.to_string(), "Baz");
+}
+
+#[test]
+fn impl_from_str() {
+    assert_eq!(str::parse::<Any>("Foo"), Ok(Any::A(AEnum::Foo)));
+    assert_eq!(str::parse::<Any>("bar"), Ok(Any::B(BEnum::Bar)));
+    assert_eq!(str::parse::<Any>("Baz"), Ok(Any::Baz));
+
+    assert_eq!(
+        str::parse::<Any>("Bar"),
+        Err(UnknownVariantError::new("Bar"))
+    );
+
+    assert_eq!(str::parse::<Reversed>("Foo"), Ok(Reversed::A(AEnum::Foo)));
+    assert_eq!(
+        str::parse::<Reversed>("qux"),
+        Ok(Reversed::Unknown("qux".into()))
+    );
+}
+
+#[test]
+fn first_match_wins() {
+    assert_eq!(str::parse::<Any>("Shared"), Ok(Any::A(AEnum::Shared)));
+    assert_eq!(str::parse::<Any>("shared"), Ok(Any::B(BEnum::Shared)));
+
+    #[derive(Debug, Eq, PartialEq, Sternum)]
+    #[sternum(transform = lowercase)]
+    enum CEnum {
+        Shared,
+    }
+
+    #[derive(Debug, Eq, PartialEq, Sternum)]
+    enum Ambiguous {
+        #[sternum(flatten)]
+        B(BEnum),
+        #[sternum(flatten)]
+        C(CEnum),
+    }
+
+    assert_eq!(
+        str::parse::<Ambiguous>("shared"),
+        Ok(Ambiguous::B(BEnum::Shared))
+    );
+}
+
+#[test]
+fn round_trip() {
+    assert_eq!(
+        str::parse::<Any>(&Any::A(AEnum::Foo).to_string()),
+        Ok(Any::A(AEnum::Foo))
+    );
+    assert_eq!(
+        str::parse::<Any>(&Any::B(BEnum::Bar).to_string()),
+        Ok(Any::B(BEnum::Bar))
+    );
+}
@@ -0,0 +1,72 @@
+// Any copyright is dedicated to the Public Domain.
+// https://creativecommons.org/publicdomain/zero/1.0/
+
+use sternum::{Sternum, UnknownVariantError};
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+#[sternum(loose)]
+enum Kind {
+    FooBar,
+    #[sternum(rename = "http.proxy", alias = "Web_Proxy")]
+    HttpProxy,
+    #[sternum(rename = "Ärger")]
+    Anger,
+    #[sternum(pattern = "foo-{0}")]
+    Numbered(u8),
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Sternum)]
+#[sternum(loose, compact, rename_all = "snake_case")]
+enum Compact {
+    FooBar,
+    Baz,
+}
+
+#[test]
+fn impl_display() {
+    assert_eq!(Kind::FooBar.to_string(), "FooBar");
+    assert_eq!(Kind::HttpProxy.to_string(), "http.proxy");
+    assert_eq!(Compact::FooBar.to_string(), "foo_bar");
+}
+
+#[test]
+fn impl_from_str() {
+    for input in &[
+        "FooBar",
+        "foobar",
+        "foo-bar",
+        "foo_bar",
+        "FOO BAR",
+        "Foo.Bar",
+        "-foo--bar-",
+        "foo\tbar",
+        " FooBar\n",
+    ] {
+        assert_eq!(str::parse::<Kind>(input), Ok(Kind::FooBar), "{:?}", input);
+        assert_eq!(
+            str::parse::<Compact>(input),
+            Ok(Compact::FooBar),
+            "{:?}",
+            input
+        );
+    }
+
+    assert_eq!(str::parse::<Kind>("HttpProxy"), Ok(Kind::HttpProxy));
+    assert_eq!(str::parse::<Kind>("web-proxy"), Ok(Kind::HttpProxy));
+    assert_eq!(str::parse::<Kind>("ÄRGER"), Ok(Kind::Anger));
+    assert_eq!(str::parse::<Kind>("foo-7"), Ok(Kind::Numbered(7)));
+    assert_eq!(str::parse::<Compact>("BAZ"), Ok(Compact::Baz));
+
+    assert_eq!(
+        str::parse::<Kind>("foo+bar"),
+        Err(UnknownVariantError::new("foo+bar"))
+    );
+    assert_eq!(
+        str::parse::<Kind>("foo-bar-baz"),
+        Err(UnknownVariantError::new("foo-bar-baz"))
+    );
+    assert_eq!(
+        str::parse::<Kind>("--"),
+        Err(UnknownVariantError::new("--"))
+    );
+}
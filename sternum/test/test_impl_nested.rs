@@ -0,0 +1,130 @@
+// Any copyright is dedicated to the Public Domain.
+// https://creativecommons.org/publicdomain/zero/1.0/
+
+use sternum::{Sternum, UnknownVariantError};
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+enum TcpEvent {
+    Connect,
+    Close,
+}
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+#[sternum(transform = lowercase)]
+enum UdpEvent {
+    Send,
+}
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+#[sternum(scoped)]
+enum Net {
+    #[sternum(nested)]
+    Tcp(TcpEvent),
+    #[sternum(nested, rename = "udp")]
+    Udp(UdpEvent),
+    Down,
+}
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+#[sternum(separator = "/", case_insensitive)]
+enum Path {
+    #[sternum(nested)]
+    Tcp(TcpEvent),
+}
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+enum Host {
+    #[sternum(nested)]
+    Net(Net),
+    Local,
+}
+
+#[test]
+fn impl_display() {
+    assert_eq!(Net::Tcp(TcpEvent::Connect).to_string(), "Net::Tcp::Connect");
+    assert_eq!(Net::Tcp(TcpEvent::Close).to_string(), "Net::Tcp::Close");
+    assert_eq!(Net::Udp(UdpEvent::Send).to_string(), "udp::send");
+    assert_eq!(Net::Down.to_string(), "Net::Down");
+
+    assert_eq!(Path::Tcp(TcpEvent::Connect).to_string(), "Tcp/Connect");
+}
+
+#[test]
+fn impl_from_str() {
+    assert_eq!(
+        str::parse::<Net>("Net::Tcp::Connect"),
+        Ok(Net::Tcp(TcpEvent::Connect))
+    );
+    assert_eq!(str::parse::<Net>("udp::send"), Ok(Net::Udp(UdpEvent::Send)));
+    assert_eq!(str::parse::<Net>("Net::Down"), Ok(Net::Down));
+
+    assert_eq!(
+        str::parse::<Net>("Net::Tcp::Open").unwrap_err().variant(),
+        "Net::Tcp::Open"
+    );
+    assert_eq!(
+        str::parse::<Net>("Net::Tcp"),
+        Err(UnknownVariantError::new("Net::Tcp"))
+    );
+    assert_eq!(
+        str::parse::<Net>("Tcp::Connect"),
+        Err(UnknownVariantError::new("Tcp::Connect"))
+    );
+
+    // The inner enum is case-sensitive, even though the outer enum is not.
+    assert_eq!(
+        str::parse::<Path>("TCP/Connect"),
+        Ok(Path::Tcp(TcpEvent::Connect))
+    );
+    assert_eq!(
+        str::parse::<Path>("tcp/connect").unwrap_err().variant(),
+        "tcp/connect"
+    );
+}
+
+#[test]
+fn impl_from_str_error() {
+    let err = str::parse::<Net>("Net::Tcp::Open").unwrap_err();
+    let nested = err.nested().unwrap();
+    assert_eq!(nested.variant(), "Open");
+    assert_eq!(nested.type_name(), "TcpEvent");
+    assert_eq!(nested.expected(), &["Connect", "Close"]);
+    assert_eq!(
+        err.to_string(),
+        "Could not parse `Net::Tcp::Open' as type Net: unknown variant `Open' of type TcpEvent \
+         (expected one of: `Connect', `Close')"
+    );
+
+    // Only the innermost nested variant is kept, and the error still names the entire input.
+    let err = str::parse::<Host>("Net::Net::Tcp::Clos").unwrap_err();
+    assert_eq!(err.variant(), "Net::Net::Tcp::Clos");
+    assert_eq!(err.nested().unwrap().variant(), "Clos");
+    assert_eq!(err.nested().unwrap().type_name(), "TcpEvent");
+    assert_eq!(
+        err.to_string(),
+        "Could not parse `Net::Net::Tcp::Clos' as type Host: unknown variant `Clos' of type \
+         TcpEvent (expected one of: `Connect', `Close'); did you mean `Close'?"
+    );
+
+    // Input that does not start with the name of a nested variant is not nested.
+    assert_eq!(
+        str::parse::<Host>("Remote"),
+        Err(UnknownVariantError::new("Remote"))
+    );
+    assert!(str::parse::<Net>("Net::Tcp")
+        .unwrap_err()
+        .nested()
+        .is_none());
+}
+
+#[test]
+fn round_trip() {
+    assert_eq!(
+        str::parse::<Net>(&Net::Tcp(TcpEvent::Close).to_string()),
+        Ok(Net::Tcp(TcpEvent::Close))
+    );
+    assert_eq!(
+        str::parse::<Net>(&Net::Udp(UdpEvent::Send).to_string()),
+        Ok(Net::Udp(UdpEvent::Send))
+    );
+}
@@ -0,0 +1,128 @@
+// Any copyright is dedicated to the Public Domain.
+// https://creativecommons.org/publicdomain/zero/1.0/
+
+use std::borrow::Cow;
+
+use sternum::{Sternum, UnknownVariantError};
+
+fn strip_v(s: &str) -> Cow<'_, str> {
+    Cow::Borrowed(s.strip_prefix('v').unwrap_or(s))
+}
+
+fn uppercase(s: &str) -> Cow<'_, str> {
+    Cow::Owned(s.to_uppercase())
+}
+
+fn remove_spaces(s: &str) -> Cow<'_, str> {
+    if s.contains(' ') {
+        Cow::Owned(s.replace(' ', ""))
+    } else {
+        Cow::Borrowed(s)
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+#[sternum(normalize = strip_v)]
+enum Version {
+    #[sternum(rename = "v1", alias = "one")]
+    V1,
+    #[sternum(rename = "2")]
+    V2,
+    #[sternum(pattern = "v{0}.x")]
+    Minor(u8),
+    #[sternum(other)]
+    Other(String),
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Sternum)]
+#[sternum(normalize = remove_spaces, case_insensitive, compact)]
+enum Spaced {
+    #[sternum(rename = "Foo Bar")]
+    FooBar,
+    Baz,
+}
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+enum Inner {
+    A,
+}
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+#[sternum(normalize = uppercase)]
+enum Shouting {
+    #[sternum(rename = "LOUD")]
+    Loud,
+    #[sternum(nested)]
+    In(Inner),
+    #[sternum(other)]
+    Other(String),
+}
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+#[sternum(normalize = strip_v)]
+enum Collision {
+    #[sternum(rename = "v1")]
+    A,
+    #[sternum(rename = "1")]
+    B,
+}
+
+#[test]
+fn impl_display() {
+    assert_eq!(Version::V1.to_string(), "v1");
+    assert_eq!(Spaced::FooBar.to_string(), "Foo Bar");
+}
+
+#[test]
+fn impl_from_str() {
+    assert_eq!(str::parse::<Version>("v1"), Ok(Version::V1));
+    assert_eq!(str::parse::<Version>("1"), Ok(Version::V1));
+    assert_eq!(str::parse::<Version>("vone"), Ok(Version::V1));
+    assert_eq!(str::parse::<Version>("v2"), Ok(Version::V2));
+    assert_eq!(str::parse::<Version>("v3.x"), Ok(Version::Minor(3)));
+    assert_eq!(
+        str::parse::<Version>("3.x"),
+        Ok(Version::Other("3.x".into()))
+    );
+    assert_eq!(str::parse::<Version>("v4"), Ok(Version::Other("v4".into())));
+
+    assert_eq!(str::parse::<Spaced>("foobar"), Ok(Spaced::FooBar));
+    assert_eq!(str::parse::<Spaced>("FOO BAR"), Ok(Spaced::FooBar));
+    assert_eq!(str::parse::<Spaced>("B a z"), Ok(Spaced::Baz));
+    assert_eq!(
+        str::parse::<Spaced>("Foo-Bar"),
+        Err(UnknownVariantError::new("Foo-Bar"))
+    );
+}
+
+#[test]
+fn impl_from_str_unnormalized() {
+    assert_eq!(str::parse::<Shouting>("loud"), Ok(Shouting::Loud));
+    assert_eq!(
+        Shouting::In(Inner::A).to_string().parse::<Shouting>(),
+        Ok(Shouting::In(Inner::A))
+    );
+    assert_eq!(
+        str::parse::<Shouting>("quiet"),
+        Ok(Shouting::Other("quiet".into()))
+    );
+}
+
+#[test]
+fn impl_from_str_error() {
+    assert_eq!(
+        str::parse::<Spaced>("Qu ux"),
+        Err(UnknownVariantError::new("Qu ux"))
+    );
+    assert_eq!(
+        str::parse::<Spaced>("Qu ux").unwrap_err().variant(),
+        "Qu ux"
+    );
+}
+
+#[test]
+fn impl_from_str_collision() {
+    assert_eq!(str::parse::<Collision>("v1"), Ok(Collision::A));
+    assert_eq!(str::parse::<Collision>("1"), Ok(Collision::A));
+    assert_eq!(Collision::B.to_string(), "1");
+}
@@ -0,0 +1,60 @@
+// Any copyright is dedicated to the Public Domain.
+// https://creativecommons.org/publicdomain/zero/1.0/
+
+use sternum::Sternum;
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+enum Enum {
+    Foo,
+    Bar,
+    #[sternum(other)]
+    Unknown(String),
+}
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+#[sternum(case_insensitive)]
+enum CaseInsensitiveEnum {
+    Foo,
+    #[sternum(other)]
+    Unknown(Box<str>),
+}
+
+#[test]
+fn impl_display() {
+    assert_eq!(Enum::Foo.to_string(), "Foo");
+    assert_eq!(Enum::Bar.to_string(), "Bar");
+    assert_eq!(Enum::Unknown("baz".into()).to_string(), "baz");
+    assert_eq!(Enum::Unknown("Foo".into()).to_string(), "Foo");
+
+    assert_eq!(
+        CaseInsensitiveEnum::Unknown("Bar".into()).to_string(),
+        "Bar"
+    );
+}
+
+#[test]
+fn impl_from_str() {
+    assert_eq!(str::parse::<Enum>("Foo"), Ok(Enum::Foo));
+    assert_eq!(str::parse::<Enum>("Bar"), Ok(Enum::Bar));
+    assert_eq!(str::parse::<Enum>("baz"), Ok(Enum::Unknown("baz".into())));
+    assert_eq!(str::parse::<Enum>("foo"), Ok(Enum::Unknown("foo".into())));
+    assert_eq!(str::parse::<Enum>(""), Ok(Enum::Unknown("".into())));
+
+    assert_eq!(
+        str::parse::<CaseInsensitiveEnum>("FOO"),
+        Ok(CaseInsensitiveEnum::Foo)
+    );
+    assert_eq!(
+        str::parse::<CaseInsensitiveEnum>("Bar"),
+        Ok(CaseInsensitiveEnum::Unknown("Bar".into()))
+    );
+}
+
+#[test]
+fn round_trip() {
+    assert_eq!(str::parse::<Enum>(&Enum::Foo.to_string()), Ok(Enum::Foo));
+    assert_eq!(
+        str::parse::<Enum>(&Enum::Unknown("baz".into()).to_string()),
+        Ok(Enum::Unknown("baz".into()))
+    );
+}
@@ -0,0 +1,69 @@
+// Any copyright is dedicated to the Public Domain.
+// https://creativecommons.org/publicdomain/zero/1.0/
+
+use sternum::Sternum;
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+enum Op {
+    #[sternum(rename = ">")]
+    Gt,
+    #[sternum(rename = ">=")]
+    Ge,
+    #[sternum(rename = "=")]
+    Assign,
+    #[sternum(rename = "==", alias = "===")]
+    Eq,
+    #[sternum(pattern = "op{0}")]
+    Custom(u8),
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Sternum)]
+#[sternum(case_insensitive, rename_all = "lowercase")]
+enum Keyword {
+    If,
+    In,
+    Int,
+    #[sternum(rename = "Ärger")]
+    Anger,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Sternum)]
+#[sternum(compact, case_insensitive, rename_all = "lowercase")]
+enum CompactKeyword {
+    If,
+    In,
+    Int,
+}
+
+#[test]
+fn parse_prefix() {
+    assert_eq!(Op::parse_prefix(">= 1"), Some((Op::Ge, " 1")));
+    assert_eq!(Op::parse_prefix("> 1"), Some((Op::Gt, " 1")));
+    assert_eq!(Op::parse_prefix(">>"), Some((Op::Gt, ">")));
+    assert_eq!(Op::parse_prefix("=1"), Some((Op::Assign, "1")));
+    assert_eq!(Op::parse_prefix("==1"), Some((Op::Eq, "1")));
+    assert_eq!(Op::parse_prefix("===1"), Some((Op::Eq, "1")));
+    assert_eq!(Op::parse_prefix("===="), Some((Op::Eq, "=")));
+    assert_eq!(Op::parse_prefix(">="), Some((Op::Ge, "")));
+
+    assert_eq!(Op::parse_prefix(""), None);
+    assert_eq!(Op::parse_prefix("< 1"), None);
+    assert_eq!(Op::parse_prefix("op1"), None);
+}
+
+#[test]
+fn parse_prefix_case_insensitive() {
+    assert_eq!(
+        Keyword::parse_prefix("INTEGER"),
+        Some((Keyword::Int, "EGER"))
+    );
+    assert_eq!(Keyword::parse_prefix("In x"), Some((Keyword::In, " x")));
+    assert_eq!(Keyword::parse_prefix("iF"), Some((Keyword::If, "")));
+    assert_eq!(Keyword::parse_prefix("ÄRGER!"), Some((Keyword::Anger, "!")));
+    assert_eq!(Keyword::parse_prefix("i"), None);
+
+    assert_eq!(
+        CompactKeyword::parse_prefix("INTEGER"),
+        Some((CompactKeyword::Int, "EGER"))
+    );
+}
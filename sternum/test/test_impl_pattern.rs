@@ -0,0 +1,86 @@
+// Any copyright is dedicated to the Public Domain.
+// https://creativecommons.org/publicdomain/zero/1.0/
+
+use sternum::{Sternum, UnknownVariantError};
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+enum Enum {
+    Auto,
+    #[sternum(pattern = "L{0}")]
+    Level(u8),
+    #[sternum(pattern = "{w}x{h}")]
+    Size {
+        w: u32,
+        h: u32,
+    },
+    #[sternum(pattern = "{{{1}, {0}}}")]
+    Pair(String, i32),
+}
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+enum OtherEnum {
+    #[sternum(pattern = "#{0}")]
+    Number(u32),
+    #[sternum(other)]
+    Unknown(String),
+}
+
+#[test]
+fn impl_display() {
+    assert_eq!(Enum::Auto.to_string(), "Auto");
+    assert_eq!(Enum::Level(3).to_string(), "L3");
+    assert_eq!(Enum::Size { w: 640, h: 480 }.to_string(), "640x480");
+    assert_eq!(Enum::Pair("a".into(), -1).to_string(), "{-1, a}");
+
+    assert_eq!(OtherEnum::Number(7).to_string(), "#7");
+}
+
+#[test]
+fn impl_from_str() {
+    assert_eq!(str::parse::<Enum>("Auto"), Ok(Enum::Auto));
+    assert_eq!(str::parse::<Enum>("L3"), Ok(Enum::Level(3)));
+    assert_eq!(
+        str::parse::<Enum>("640x480"),
+        Ok(Enum::Size { w: 640, h: 480 })
+    );
+    assert_eq!(
+        str::parse::<Enum>("{-1, a, b}"),
+        Ok(Enum::Pair("a, b".into(), -1))
+    );
+
+    assert_eq!(str::parse::<Enum>("L"), Err(UnknownVariantError::new("L")));
+    assert_eq!(
+        str::parse::<Enum>("L256"),
+        Err(UnknownVariantError::new("L256"))
+    );
+    assert_eq!(
+        str::parse::<Enum>("640x"),
+        Err(UnknownVariantError::new("640x"))
+    );
+    assert_eq!(
+        str::parse::<Enum>("640x480x1"),
+        Err(UnknownVariantError::new("640x480x1"))
+    );
+
+    assert_eq!(str::parse::<OtherEnum>("#7"), Ok(OtherEnum::Number(7)));
+    assert_eq!(
+        str::parse::<OtherEnum>("#seven"),
+        Ok(OtherEnum::Unknown("#seven".into()))
+    );
+}
+
+#[test]
+fn round_trip() {
+    assert_eq!(
+        str::parse::<Enum>(&Enum::Level(255).to_string()),
+        Ok(Enum::Level(255))
+    );
+    assert_eq!(
+        str::parse::<Enum>(&Enum::Size { w: 1, h: 2 }.to_string()),
+        Ok(Enum::Size { w: 1, h: 2 })
+    );
+    assert_eq!(
+        str::parse::<Enum>(&Enum::Pair("x".into(), 2).to_string()),
+        Ok(Enum::Pair("x".into(), 2))
+    );
+}
@@ -0,0 +1,74 @@
+// Any copyright is dedicated to the Public Domain.
+// https://creativecommons.org/publicdomain/zero/1.0/
+
+use sternum::{Sternum, UnknownVariantError};
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+enum Enum {
+    #[sternum(rename = "https-proxy")]
+    HttpsProxy,
+    #[sternum(rename = "legacy_name")]
+    Current,
+    Unchanged,
+}
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+#[sternum(scoped, transform = uppercase)]
+enum ScopedEnum {
+    #[sternum(rename = "foo")]
+    Foo,
+    Bar,
+}
+
+#[test]
+fn impl_display() {
+    assert_eq!(Enum::HttpsProxy.to_string(), "https-proxy");
+    assert_eq!(Enum::Current.to_string(), "legacy_name");
+    assert_eq!(Enum::Unchanged.to_string(), "Unchanged");
+
+    assert_eq!(ScopedEnum::Foo.to_string(), "foo");
+    assert_eq!(ScopedEnum::Bar.to_string(), "SCOPEDENUM::BAR");
+}
+
+#[test]
+fn impl_from_str() {
+    assert_eq!(str::parse::<Enum>("https-proxy"), Ok(Enum::HttpsProxy));
+    assert_eq!(str::parse::<Enum>("legacy_name"), Ok(Enum::Current));
+    assert_eq!(str::parse::<Enum>("Unchanged"), Ok(Enum::Unchanged));
+
+    assert_eq!(
+        str::parse::<Enum>("HttpsProxy"),
+        Err(UnknownVariantError::new("HttpsProxy"))
+    );
+    assert_eq!(
+        str::parse::<Enum>("Current"),
+        Err(UnknownVariantError::new("Current"))
+    );
+
+    assert_eq!(str::parse::<ScopedEnum>("foo"), Ok(ScopedEnum::Foo));
+    assert_eq!(
+        str::parse::<ScopedEnum>("SCOPEDENUM::BAR"),
+        Ok(ScopedEnum::Bar)
+    );
+    assert_eq!(
+        str::parse::<ScopedEnum>("SCOPEDENUM::FOO"),
+        Err(UnknownVariantError::new("SCOPEDENUM::FOO"))
+    );
+}
+
+#[test]
+#[allow(clippy::unnecessary_to_owned)]
+fn round_trip() {
+    assert_eq!(
+        str::parse::<Enum>(&Enum::HttpsProxy.to_string()),
+        Ok(Enum::HttpsProxy)
+    );
+    assert_eq!(
+        str::parse::<Enum>(&Enum::Current.to_string()),
+        Ok(Enum::Current)
+    );
+    assert_eq!(
+        str::parse::<Enum>(&Enum::Unchanged.to_string()),
+        Ok(Enum::Unchanged)
+    );
+}
@@ -0,0 +1,128 @@
+// Any copyright is dedicated to the Public Domain.
+// https://creativecommons.org/publicdomain/zero/1.0/
+
+use sternum::{Sternum, UnknownVariantError};
+
+macro_rules! case_style_enum {
+    ($name:ident, $($attr:tt)*) => {
+        #[derive(Debug, Eq, PartialEq, Sternum)]
+        #[sternum($($attr)*)]
+        #[allow(non_camel_case_types)]
+        enum $name {
+            LoudNoises,
+            HTTPSProxy,
+            Utf8Error,
+            Snake_Variant,
+            A,
+        }
+    };
+}
+
+case_style_enum!(SnakeCase, transform = snake_case);
+case_style_enum!(KebabCase, rename_all = "kebab-case");
+case_style_enum!(ScreamingSnakeCase, rename_all = "SCREAMING_SNAKE_CASE");
+case_style_enum!(ScreamingKebabCase, transform = screaming_kebab_case);
+case_style_enum!(CamelCase, rename_all = "camelCase");
+case_style_enum!(PascalCase, transform = pascal_case);
+case_style_enum!(TrainCase, rename_all = "Train-Case");
+case_style_enum!(DotCase, transform = dot_case);
+case_style_enum!(TitleCase, rename_all = "Title Case");
+case_style_enum!(Uppercase, rename_all = "UPPERCASE");
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+#[sternum(scoped, rename_all = "snake_case")]
+enum ScopedEnum {
+    LoudNoises,
+}
+
+#[test]
+fn impl_display() {
+    assert_eq!(SnakeCase::LoudNoises.to_string(), "loud_noises");
+    assert_eq!(SnakeCase::HTTPSProxy.to_string(), "https_proxy");
+    assert_eq!(SnakeCase::Utf8Error.to_string(), "utf8_error");
+    assert_eq!(SnakeCase::Snake_Variant.to_string(), "snake_variant");
+    assert_eq!(SnakeCase::A.to_string(), "a");
+
+    assert_eq!(KebabCase::LoudNoises.to_string(), "loud-noises");
+    assert_eq!(KebabCase::HTTPSProxy.to_string(), "https-proxy");
+    assert_eq!(KebabCase::Utf8Error.to_string(), "utf8-error");
+
+    assert_eq!(ScreamingSnakeCase::LoudNoises.to_string(), "LOUD_NOISES");
+    assert_eq!(ScreamingSnakeCase::HTTPSProxy.to_string(), "HTTPS_PROXY");
+
+    assert_eq!(ScreamingKebabCase::LoudNoises.to_string(), "LOUD-NOISES");
+    assert_eq!(
+        ScreamingKebabCase::Snake_Variant.to_string(),
+        "SNAKE-VARIANT"
+    );
+
+    assert_eq!(CamelCase::LoudNoises.to_string(), "loudNoises");
+    assert_eq!(CamelCase::HTTPSProxy.to_string(), "httpsProxy");
+    assert_eq!(CamelCase::Snake_Variant.to_string(), "snakeVariant");
+
+    assert_eq!(PascalCase::LoudNoises.to_string(), "LoudNoises");
+    assert_eq!(PascalCase::HTTPSProxy.to_string(), "HttpsProxy");
+    assert_eq!(PascalCase::Snake_Variant.to_string(), "SnakeVariant");
+
+    assert_eq!(TrainCase::LoudNoises.to_string(), "Loud-Noises");
+    assert_eq!(TrainCase::Utf8Error.to_string(), "Utf8-Error");
+
+    assert_eq!(DotCase::LoudNoises.to_string(), "loud.noises");
+    assert_eq!(DotCase::HTTPSProxy.to_string(), "https.proxy");
+
+    assert_eq!(TitleCase::LoudNoises.to_string(), "Loud Noises");
+    assert_eq!(TitleCase::HTTPSProxy.to_string(), "Https Proxy");
+
+    assert_eq!(Uppercase::LoudNoises.to_string(), "LOUDNOISES");
+
+    assert_eq!(
+        ScopedEnum::LoudNoises.to_string(),
+        "scoped_enum::loud_noises"
+    );
+}
+
+#[test]
+fn impl_from_str() {
+    assert_eq!(
+        str::parse::<SnakeCase>("loud_noises"),
+        Ok(SnakeCase::LoudNoises)
+    );
+    assert_eq!(
+        str::parse::<KebabCase>("https-proxy"),
+        Ok(KebabCase::HTTPSProxy)
+    );
+    assert_eq!(
+        str::parse::<TitleCase>("Utf8 Error"),
+        Ok(TitleCase::Utf8Error)
+    );
+    assert_eq!(
+        str::parse::<ScopedEnum>("scoped_enum::loud_noises"),
+        Ok(ScopedEnum::LoudNoises)
+    );
+
+    assert_eq!(
+        str::parse::<SnakeCase>("LoudNoises"),
+        Err(UnknownVariantError::new("LoudNoises"))
+    );
+    assert_eq!(
+        str::parse::<KebabCase>("loud_noises"),
+        Err(UnknownVariantError::new("loud_noises"))
+    );
+}
+
+#[test]
+#[allow(clippy::unnecessary_to_owned)]
+fn round_trip() {
+    assert_eq!(
+        str::parse::<CamelCase>(&CamelCase::HTTPSProxy.to_string()),
+        Ok(CamelCase::HTTPSProxy)
+    );
+    assert_eq!(
+        str::parse::<TrainCase>(&TrainCase::Snake_Variant.to_string()),
+        Ok(TrainCase::Snake_Variant)
+    );
+    assert_eq!(
+        str::parse::<DotCase>(&DotCase::Utf8Error.to_string()),
+        Ok(DotCase::Utf8Error)
+    );
+}
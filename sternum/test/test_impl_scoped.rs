@@ -39,6 +39,7 @@ fn impl_from_str() {
 }
 
 #[test]
+#[allow(clippy::unnecessary_to_owned)]
 fn round_trip() {
     assert_eq!(str::parse::<Enum>(&Enum::Foo.to_string()), Ok(Enum::Foo));
     assert_eq!(str::parse::<Enum>(&Enum::Bar.to_string()), Ok(Enum::Bar));
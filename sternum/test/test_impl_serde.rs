@@ -0,0 +1,109 @@
+// Any copyright is dedicated to the Public Domain.
+// https://creativecommons.org/publicdomain/zero/1.0/
+
+use std::collections::BTreeMap;
+
+use sternum::Sternum;
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd, Sternum)]
+#[sternum(serde, rename_all = "kebab-case", case_insensitive)]
+enum Enum {
+    LoudNoises,
+    #[sternum(alias = "hush")]
+    Quiet,
+}
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+#[sternum(serde, scoped)]
+enum ScopedEnum {
+    Foo,
+}
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+#[sternum(serde)]
+enum Setting {
+    Auto,
+    #[sternum(pattern = "L{0}")]
+    Level(u8),
+}
+
+/// An enum without `#[sternum(serde)]`, which implements serde's traits itself.
+#[derive(Debug, Eq, PartialEq, Sternum)]
+enum Manual {
+    Foo,
+}
+
+impl serde::Serialize for Manual {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u8(0)
+    }
+}
+
+#[test]
+fn serialize() {
+    assert_eq!(
+        serde_json::to_string(&Enum::LoudNoises).unwrap(),
+        "\"loud-noises\""
+    );
+    assert_eq!(
+        serde_json::to_string(&ScopedEnum::Foo).unwrap(),
+        "\"ScopedEnum::Foo\""
+    );
+    assert_eq!(serde_json::to_string(&Setting::Level(3)).unwrap(), "\"L3\"");
+
+    let map: BTreeMap<Enum, u32> = vec![(Enum::Quiet, 1)].into_iter().collect();
+    assert_eq!(serde_json::to_string(&map).unwrap(), "{\"quiet\":1}");
+
+    assert_eq!(serde_json::to_string(&Manual::Foo).unwrap(), "0");
+}
+
+#[test]
+fn deserialize() {
+    assert_eq!(
+        serde_json::from_str::<Enum>("\"loud-noises\"").unwrap(),
+        Enum::LoudNoises
+    );
+    assert_eq!(
+        serde_json::from_str::<Enum>("\"LOUD-NOISES\"").unwrap(),
+        Enum::LoudNoises
+    );
+    assert_eq!(
+        serde_json::from_str::<Enum>("\"hush\"").unwrap(),
+        Enum::Quiet
+    );
+    assert_eq!(
+        serde_json::from_str::<ScopedEnum>("\"ScopedEnum::Foo\"").unwrap(),
+        ScopedEnum::Foo
+    );
+    assert_eq!(
+        serde_json::from_str::<Setting>("\"L3\"").unwrap(),
+        Setting::Level(3)
+    );
+
+    // Strings with escapes cannot be borrowed from the input.
+    assert_eq!(
+        serde_json::from_str::<Enum>("\"qu\\u0069et\"").unwrap(),
+        Enum::Quiet
+    );
+
+    let map: BTreeMap<Enum, u32> = serde_json::from_str("{\"quiet\":1}").unwrap();
+    assert_eq!(map.get(&Enum::Quiet), Some(&1));
+}
+
+#[test]
+fn deserialize_error() {
+    let err = serde_json::from_str::<Enum>("\"loud\"").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "unknown variant `loud`, expected `loud-noises` or `quiet` at line 1 column 6"
+    );
+
+    let err = serde_json::from_str::<Enum>("1").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid type: integer `1`, expected a variant of Enum at line 1 column 1"
+    );
+}
@@ -0,0 +1,78 @@
+// Any copyright is dedicated to the Public Domain.
+// https://creativecommons.org/publicdomain/zero/1.0/
+
+use sternum::Sternum;
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+enum Enum {
+    Foo,
+    Bar,
+    Baz,
+}
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+#[sternum(scoped, rename_all = "kebab-case")]
+enum ScopedEnum {
+    LoudNoises,
+    #[sternum(rename = "quiet", alias = "hush")]
+    Quiet,
+}
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+enum DataEnum {
+    Foo,
+    #[sternum(pattern = "L{0}")]
+    Level(u8),
+    Bar,
+    #[sternum(other)]
+    Unknown(String),
+}
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+enum DropEnum {
+    Foo,
+}
+
+impl Drop for DropEnum {
+    fn drop(&mut self) {}
+}
+
+#[test]
+fn variants() {
+    assert_eq!(Enum::VARIANTS, &[Enum::Foo, Enum::Bar, Enum::Baz]);
+    assert_eq!(
+        ScopedEnum::VARIANTS,
+        &[ScopedEnum::LoudNoises, ScopedEnum::Quiet]
+    );
+    assert_eq!(DataEnum::VARIANTS, &[DataEnum::Foo, DataEnum::Bar]);
+    assert_eq!(DropEnum::VARIANTS, &[DropEnum::Foo]);
+}
+
+#[test]
+fn names() {
+    assert_eq!(Enum::NAMES, &["Foo", "Bar", "Baz"]);
+    assert_eq!(ScopedEnum::NAMES, &["scoped-enum::loud-noises", "quiet"]);
+    assert_eq!(DataEnum::NAMES, &["Foo", "Bar"]);
+
+    for (variant, name) in Enum::VARIANTS.iter().zip(Enum::NAMES) {
+        assert_eq!(&variant.to_string(), name);
+    }
+}
+
+#[test]
+fn count() {
+    assert_eq!(Enum::COUNT, 3);
+    assert_eq!(ScopedEnum::COUNT, 2);
+    assert_eq!(DataEnum::COUNT, 2);
+
+    const COUNT: usize = <Enum as Sternum>::COUNT;
+    assert_eq!(COUNT, 3);
+}
+
+#[test]
+fn unit_only() {
+    assert_eq!(
+        [Enum::UNIT_ONLY, ScopedEnum::UNIT_ONLY, DataEnum::UNIT_ONLY],
+        [true, true, false]
+    );
+}
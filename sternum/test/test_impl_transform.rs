@@ -68,6 +68,7 @@ fn impl_from_str() {
 }
 
 #[test]
+#[allow(clippy::unnecessary_to_owned)]
 fn round_trip() {
     assert_eq!(
         str::parse::<UppercaseEnum>(&UppercaseEnum::Foo.to_string()),
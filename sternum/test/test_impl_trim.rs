@@ -0,0 +1,69 @@
+// Any copyright is dedicated to the Public Domain.
+// https://creativecommons.org/publicdomain/zero/1.0/
+
+use std::borrow::Cow;
+
+use sternum::{Sternum, UnknownVariantError};
+
+fn strip_v(s: &str) -> Cow<'_, str> {
+    Cow::Borrowed(s.strip_prefix('v').unwrap_or(s))
+}
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+#[sternum(trim)]
+enum Kind {
+    Foo,
+    #[sternum(rename = "Foo Bar")]
+    FooBar,
+    #[sternum(pattern = "Baz{0}")]
+    Baz(u8),
+    #[sternum(other)]
+    Other(String),
+}
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+#[sternum(trim, case_insensitive)]
+enum Strict {
+    Foo,
+}
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+#[sternum(trim, normalize = strip_v)]
+enum Version {
+    #[sternum(rename = "1")]
+    V1,
+}
+
+#[test]
+fn impl_from_str() {
+    assert_eq!(str::parse::<Kind>("Foo"), Ok(Kind::Foo));
+    assert_eq!(str::parse::<Kind>("Foo\n"), Ok(Kind::Foo));
+    assert_eq!(str::parse::<Kind>(" \tFoo\r\n"), Ok(Kind::Foo));
+    assert_eq!(str::parse::<Kind>("\u{a0}Foo\u{2003}"), Ok(Kind::Foo));
+    assert_eq!(str::parse::<Kind>("  Foo Bar  "), Ok(Kind::FooBar));
+    assert_eq!(str::parse::<Kind>(" Baz7 "), Ok(Kind::Baz(7)));
+    assert_eq!(str::parse::<Kind>(" Qux\n"), Ok(Kind::Other("Qux".into())));
+
+    assert_eq!(str::parse::<Strict>("\tFOO\n"), Ok(Strict::Foo));
+    assert_eq!(str::parse::<Version>(" v1 "), Ok(Version::V1));
+}
+
+#[test]
+fn impl_from_str_error() {
+    assert_eq!(
+        str::parse::<Strict>(" Bar\n"),
+        Err(UnknownVariantError::new(" Bar\n"))
+    );
+    assert_eq!(
+        str::parse::<Strict>(" Bar\n").unwrap_err().variant(),
+        " Bar\n"
+    );
+    assert_eq!(
+        str::parse::<Strict>("F oo"),
+        Err(UnknownVariantError::new("F oo"))
+    );
+    assert_eq!(
+        str::parse::<Strict>("  "),
+        Err(UnknownVariantError::new("  "))
+    );
+}
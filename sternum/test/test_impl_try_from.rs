@@ -0,0 +1,63 @@
+// Any copyright is dedicated to the Public Domain.
+// https://creativecommons.org/publicdomain/zero/1.0/
+
+use std::borrow::Cow;
+use std::convert::{TryFrom, TryInto};
+
+use sternum::{Sternum, UnknownVariantError};
+
+#[derive(Debug, Eq, PartialEq, Sternum)]
+#[sternum(case_insensitive)]
+enum Enum {
+    Foo,
+    #[sternum(alias = "old_bar")]
+    Bar,
+}
+
+fn build<K>(kind: K) -> Result<Enum, UnknownVariantError<Enum>>
+where
+    K: TryInto<Enum, Error = UnknownVariantError<Enum>>,
+{
+    kind.try_into()
+}
+
+#[test]
+fn try_from_str() {
+    assert_eq!(Enum::try_from("Foo"), Ok(Enum::Foo));
+    assert_eq!(Enum::try_from("BAR"), Ok(Enum::Bar));
+    assert_eq!(Enum::try_from("old_bar"), Ok(Enum::Bar));
+
+    assert_eq!(Enum::try_from("baz"), Err(UnknownVariantError::new("baz")));
+}
+
+#[test]
+fn try_from_string() {
+    assert_eq!(Enum::try_from(String::from("foo")), Ok(Enum::Foo));
+    assert_eq!(
+        Enum::try_from(String::from("baz")),
+        Err(UnknownVariantError::new("baz"))
+    );
+}
+
+#[test]
+fn try_from_cow() {
+    assert_eq!(Enum::try_from(Cow::Borrowed("foo")), Ok(Enum::Foo));
+    assert_eq!(
+        Enum::try_from(Cow::<str>::Owned("bar".into())),
+        Ok(Enum::Bar)
+    );
+    assert_eq!(
+        Enum::try_from(Cow::Borrowed("baz")),
+        Err(UnknownVariantError::new("baz"))
+    );
+}
+
+#[test]
+fn try_into() {
+    assert_eq!(build("foo"), Ok(Enum::Foo));
+    assert_eq!(build(String::from("bar")), Ok(Enum::Bar));
+    assert_eq!(
+        build(Cow::Borrowed("baz")),
+        Err(UnknownVariantError::new("baz"))
+    );
+}
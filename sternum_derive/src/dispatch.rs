@@ -0,0 +1,359 @@
+// Copyright 2019 Barret Rennie
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeMap;
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{LitByteStr, LitStr};
+
+use crate::features::DispatchKind;
+
+/// The average number of keys in each bucket of a perfect hash table.
+const PHF_LAMBDA: usize = 5;
+
+/// A name to look up, along with the expression to evaluate when the input matches it.
+pub struct Entry {
+    pub key: String,
+    pub then: TokenStream,
+}
+
+/// Generate a lookup of the input string `s` in the entries.
+///
+/// The generated code evaluates the expression of the matching entry, if there is one, and
+/// otherwise does nothing. When `ignore_ascii_case` is set, the keys must be lowercase and the
+/// input must be ASCII.
+pub fn lookup(kind: DispatchKind, entries: &[Entry], ignore_ascii_case: bool) -> TokenStream {
+    if entries.is_empty() {
+        return TokenStream::new();
+    }
+
+    match kind {
+        DispatchKind::Match if ignore_ascii_case => by_length(entries),
+        DispatchKind::Match => by_match(entries),
+        DispatchKind::Phf => by_phf(entries, ignore_ascii_case),
+        DispatchKind::Trie => {
+            let entries: Vec<_> = entries
+                .iter()
+                .map(|entry| (entry.key.as_bytes(), &entry.then))
+                .collect();
+            let root = trie_node(&entries, 0, ignore_ascii_case);
+
+            quote! {
+                let bytes = s.as_bytes();
+                #root
+            }
+        }
+    }
+}
+
+/// A `match` on the input.
+fn by_match(entries: &[Entry]) -> TokenStream {
+    let arms = entries.iter().map(|Entry { key, then }| {
+        let lit = LitStr::new(key, Span::call_site());
+        quote! { #lit => #then, }
+    });
+
+    quote! {
+        match s {
+            #(#arms)*
+            _ => {}
+        }
+    }
+}
+
+/// A `match` on the length of the input, followed by comparisons that ignore ASCII case.
+fn by_length(entries: &[Entry]) -> TokenStream {
+    let mut by_len = BTreeMap::<usize, Vec<TokenStream>>::new();
+
+    for Entry { key, then } in entries {
+        by_len.entry(key.len()).or_default().push(quote! {
+            if s.eq_ignore_ascii_case(#key) {
+                #then
+            }
+        });
+    }
+
+    let lens = by_len.keys();
+    let comparisons = by_len.values();
+
+    quote! {
+        match s.len() {
+            #(#lens => { #(#comparisons)* })*
+            _ => {}
+        }
+    }
+}
+
+/// A `match` on each byte of the input in turn, starting at `depth`.
+///
+/// Runs of bytes that every remaining key shares, and the rest of the last remaining key, are
+/// compared all at once instead of one byte at a time. The caller guarantees that the input is at
+/// least `depth` bytes long.
+fn trie_node(
+    entries: &[(&[u8], &TokenStream)],
+    depth: usize,
+    ignore_ascii_case: bool,
+) -> TokenStream {
+    let eq = |bytes: TokenStream, lit: &[u8]| {
+        let lit = LitByteStr::new(lit, Span::call_site());
+
+        if ignore_ascii_case {
+            quote! { #bytes.eq_ignore_ascii_case(#lit) }
+        } else {
+            quote! { &#bytes == #lit }
+        }
+    };
+
+    if let [(key, then)] = entries {
+        let eq = eq(quote! { bytes[#depth..] }, &key[depth..]);
+
+        return quote! {
+            if #eq {
+                #then
+            }
+        };
+    }
+
+    let common = entries
+        .iter()
+        .map(|(key, _)| &key[depth..])
+        .reduce(|a, b| &a[..a.iter().zip(b).take_while(|(a, b)| a == b).count()])
+        .map_or(0, <[u8]>::len);
+
+    if common > 0 {
+        let end = depth + common;
+        let eq = eq(quote! { bytes[#depth..#end] }, &entries[0].0[depth..end]);
+        let next = trie_node(entries, end, ignore_ascii_case);
+
+        return quote! {
+            if bytes.len() >= #end && #eq {
+                #next
+            }
+        };
+    }
+
+    let mut end = None;
+    let mut children = BTreeMap::<u8, Vec<(&[u8], &TokenStream)>>::new();
+
+    for &(key, then) in entries {
+        match key.get(depth) {
+            Some(&byte) => children.entry(byte).or_default().push((key, then)),
+            None => end = Some(then),
+        }
+    }
+
+    let byte = if ignore_ascii_case {
+        quote! { bytes.get(#depth).map(u8::to_ascii_lowercase) }
+    } else {
+        quote! { bytes.get(#depth).copied() }
+    };
+
+    let end = end.map(|then| quote! { ::core::option::Option::None => #then, });
+
+    let arms = children.iter().map(|(byte, entries)| {
+        let lit = syn::LitByte::new(*byte, Span::call_site());
+        let next = trie_node(entries, depth + 1, ignore_ascii_case);
+
+        quote! { ::core::option::Option::Some(#lit) => #next, }
+    });
+
+    quote! {
+        match #byte {
+            #end
+            #(#arms)*
+            _ => {}
+        }
+    }
+}
+
+/// A compile-time perfect hash table, followed by a comparison with the one key that the input
+/// hashes to.
+fn by_phf(entries: &[Entry], ignore_ascii_case: bool) -> TokenStream {
+    let keys: Vec<&[u8]> = entries.iter().map(|entry| entry.key.as_bytes()).collect();
+    let (seed, table) = (0..)
+        .find_map(|seed| build_phf(&keys, seed, ignore_ascii_case).map(|table| (seed, table)))
+        .expect("the keys are unique, so some seed must produce a perfect hash");
+
+    let mask = table.slots.len() as u32 - 1;
+    let displacements = table
+        .displacements
+        .iter()
+        .map(|(d1, d2)| quote! { (#d1, #d2) });
+
+    let arms = table.slots.iter().enumerate().filter_map(|(slot, i)| {
+        let Entry { key, then } = &entries[(*i)?];
+        let slot = slot as u32;
+        let lit = LitStr::new(key, Span::call_site());
+
+        Some(if ignore_ascii_case {
+            quote! { #slot => if s.eq_ignore_ascii_case(#lit) { #then }, }
+        } else {
+            quote! { #slot => if s == #lit { #then }, }
+        })
+    });
+
+    quote! {
+        const DISPLACEMENTS: &[(u32, u32)] = &[#(#displacements),*];
+
+        match ::sternum::__private::phf_slot(
+            s.as_bytes(),
+            #seed,
+            #ignore_ascii_case,
+            DISPLACEMENTS,
+            #mask,
+        ) {
+            #(#arms)*
+            _ => {}
+        }
+    }
+}
+
+/// A perfect hash table.
+struct PhfTable {
+    /// The pair of displacements of each bucket.
+    displacements: Vec<(u32, u32)>,
+
+    /// The index of the key in each slot, if any.
+    slots: Vec<Option<usize>>,
+}
+
+/// Attempt to build a perfect hash table of the keys with the "hash, displace, and compress"
+/// algorithm.
+///
+/// The keys are divided into buckets, and then, from the largest bucket to the smallest, a pair
+/// of displacements is found that places every key in the bucket into an empty slot. The number
+/// of slots is rounded up to a power of two so that finding a slot does not need a division.
+///
+/// This returns `None` if no displacements work for some bucket with this seed.
+fn build_phf(keys: &[&[u8]], seed: u64, ignore_ascii_case: bool) -> Option<PhfTable> {
+    let len = keys.len().next_power_of_two() as u32;
+    let mask = len - 1;
+    let buckets_len = keys.len().div_ceil(PHF_LAMBDA);
+
+    let hashes: Vec<_> = keys
+        .iter()
+        .map(|key| phf_hash(key, seed, ignore_ascii_case))
+        .collect();
+
+    let mut buckets = vec![vec![]; buckets_len];
+    for (i, &(g, _, _)) in hashes.iter().enumerate() {
+        buckets[reduce(g, buckets_len as u32) as usize].push(i);
+    }
+
+    let mut order: Vec<usize> = (0..buckets_len).collect();
+    order.sort_by_key(|&bucket| std::cmp::Reverse(buckets[bucket].len()));
+
+    let mut slots: Vec<Option<usize>> = vec![None; len as usize];
+    let mut displacements = vec![(0, 0); buckets_len];
+
+    // The slots taken by the current attempt at placing a bucket, marked with the number of the
+    // attempt so that they do not need to be cleared between attempts.
+    let mut attempts = vec![0u64; len as usize];
+    let mut attempt = 0;
+    let mut placed = vec![];
+
+    'buckets: for bucket in order {
+        for d1 in 0..len {
+            'displacements: for d2 in 0..len {
+                attempt += 1;
+                placed.clear();
+
+                for &i in &buckets[bucket] {
+                    let (_, f1, f2) = hashes[i];
+                    let slot = d2.wrapping_add(f1.wrapping_mul(d1)).wrapping_add(f2) & mask;
+
+                    if slots[slot as usize].is_some() || attempts[slot as usize] == attempt {
+                        continue 'displacements;
+                    }
+
+                    attempts[slot as usize] = attempt;
+                    placed.push((slot, i));
+                }
+
+                for &(slot, i) in &placed {
+                    slots[slot as usize] = Some(i);
+                }
+
+                displacements[bucket] = (d1, d2);
+                continue 'buckets;
+            }
+        }
+
+        return None;
+    }
+
+    Some(PhfTable {
+        displacements,
+        slots,
+    })
+}
+
+// The following must be kept in sync with `sternum::__private::phf_slot`.
+
+fn phf_hash(key: &[u8], seed: u64, ignore_ascii_case: bool) -> (u32, u32, u32) {
+    let mut hash = seed ^ (key.len() as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+
+    // The key is hashed eight bytes at a time, with the last word padded with zeroes.
+    let mut chunks = key.chunks_exact(8);
+    let rest = chunks.remainder();
+
+    for chunk in chunks.by_ref() {
+        let mut word = [0; 8];
+        word.copy_from_slice(chunk);
+        hash = hash_word(hash, u64::from_le_bytes(word), ignore_ascii_case);
+    }
+
+    if !rest.is_empty() {
+        let word = rest
+            .iter()
+            .rev()
+            .fold(0, |word, &byte| (word << 8) | u64::from(byte));
+        hash = hash_word(hash, word, ignore_ascii_case);
+    }
+
+    let a = mix(hash);
+    let b = mix(a);
+
+    ((a >> 32) as u32, a as u32, b as u32)
+}
+
+fn hash_word(hash: u64, word: u64, ignore_ascii_case: bool) -> u64 {
+    let word = if ignore_ascii_case {
+        word_to_ascii_lowercase(word)
+    } else {
+        word
+    };
+
+    (hash.rotate_left(5) ^ word).wrapping_mul(0x517c_c1b7_2722_0a95)
+}
+
+/// Lowercase each byte of a word, which must only contain ASCII bytes.
+fn word_to_ascii_lowercase(word: u64) -> u64 {
+    const LSB: u64 = 0x0101_0101_0101_0101;
+
+    // The high bit of each byte is set when the byte is at least `A' or greater than `Z',
+    // respectively.
+    let ge_a = word + LSB * (0x80 - u64::from(b'A'));
+    let gt_z = word + LSB * (0x80 - u64::from(b'Z') - 1);
+    let upper = ge_a & !gt_z & (LSB * 0x80);
+
+    word | (upper >> 2)
+}
+
+fn mix(mut z: u64) -> u64 {
+    z = z.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Map `x` into the range `0..n` without dividing.
+fn reduce(x: u32, n: u32) -> u32 {
+    ((u64::from(x) * u64::from(n)) >> 32) as u32
+}
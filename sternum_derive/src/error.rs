@@ -15,7 +15,7 @@ pub struct ErrorList(pub Vec<Error>);
 
 impl ErrorList {
     /// Transform the list of errors into a list of compile errors.
-    pub fn to_compile_error(self) -> TokenStream {
+    pub fn to_compile_error(&self) -> TokenStream {
         let errors = self.0.iter().map(Error::to_compile_error);
 
         quote! {
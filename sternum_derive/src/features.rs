@@ -7,6 +7,7 @@
 // except according to those terms.
 
 mod parse;
+mod transform;
 
 use std::convert::TryFrom;
 
@@ -19,16 +20,113 @@ use crate::features::parse::{RawFeature, RawFeatures};
 /// The set of features that the Sternum derive should use.
 #[derive(Debug, Default, Eq, PartialEq)]
 pub struct FeatureSet {
+    pub abbrev: bool,
     pub case_insensitive: bool,
+    pub clap: bool,
+    pub compact: bool,
+    pub dispatch: Option<DispatchKind>,
+    pub find_iter: bool,
+    pub loose: bool,
+    pub normalize: Option<syn::Path>,
     pub scoped: bool,
+    pub separator: Option<syn::LitStr>,
+    pub serde: bool,
     pub transform: Option<TransformKind>,
+    pub trim: bool,
 }
 
-/// An uppercase or lowercase transform.
-#[derive(Debug, Eq, PartialEq)]
+impl FeatureSet {
+    /// The separator between the name of the enumeration and its variants when scoped and
+    /// between the prefix of a nested variant and its inner value.
+    pub fn separator(&self) -> String {
+        match self.separator {
+            Some(ref separator) => separator.value(),
+            None => "::".into(),
+        }
+    }
+
+    /// How the names of the variants are looked up when parsing.
+    pub fn dispatch(&self) -> DispatchKind {
+        self.dispatch.unwrap_or(DispatchKind::Match)
+    }
+}
+
+/// The set of features that apply to a single variant.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct VariantFeatureSet {
+    /// Additional names that the variant can be parsed from.
+    pub aliases: Vec<syn::LitStr>,
+
+    /// Whether or not the variant delegates to another Sternum enum without a prefix.
+    pub flatten: bool,
+
+    /// Whether or not the variant delegates to another Sternum enum.
+    pub nested: bool,
+
+    /// Whether or not the variant captures strings that do not match any other variant.
+    pub other: bool,
+
+    /// The template that the variant's fields are displayed and parsed with.
+    pub pattern: Option<syn::LitStr>,
+
+    /// The name to use for the variant instead of its identifier.
+    pub rename: Option<syn::LitStr>,
+}
+
+/// A transform applied to the names of the enumeration and its variants.
+///
+/// Apart from `Uppercase` and `Lowercase`, which change the case of the entire name, transforms
+/// split names into words and join them in the given case style.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum TransformKind {
     Uppercase,
     Lowercase,
+    SnakeCase,
+    KebabCase,
+    ScreamingSnakeCase,
+    ScreamingKebabCase,
+    CamelCase,
+    PascalCase,
+    TrainCase,
+    DotCase,
+    TitleCase,
+}
+
+/// How the generated `FromStr` implementation looks up the names of the variants.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DispatchKind {
+    /// A `match` on the string.
+    Match,
+
+    /// A compile-time perfect hash table.
+    Phf,
+
+    /// A `match` on each byte of the string in turn.
+    Trie,
+}
+
+impl DispatchKind {
+    /// The dispatch kinds, along with their names for `#[sternum(dispatch = "...")]`.
+    const NAMES: &'static [(DispatchKind, &'static str)] = &[
+        (DispatchKind::Match, "match"),
+        (DispatchKind::Phf, "phf"),
+        (DispatchKind::Trie, "trie"),
+    ];
+
+    fn from_name(name: &str) -> Option<Self> {
+        DispatchKind::NAMES
+            .iter()
+            .find(|(_, dispatch_name)| *dispatch_name == name)
+            .map(|(kind, _)| *kind)
+    }
+
+    fn names() -> String {
+        DispatchKind::NAMES
+            .iter()
+            .map(|(_, name)| format!("`{}'", name))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
 }
 
 /// A singular feature that effects the behaviour of Sternum.
@@ -48,19 +146,65 @@ impl TryFrom<RawFeature> for Feature {
 
     fn try_from(raw: RawFeature) -> Result<Self, Self::Error> {
         let kind = match raw {
+            RawFeature::Abbrev { .. } => FeatureKind::Abbrev,
+            RawFeature::Alias { ref value, .. } => FeatureKind::Alias(value.clone()),
             RawFeature::CaseInsensitive { .. } => FeatureKind::CaseInsensitive,
+            RawFeature::Clap { .. } => FeatureKind::Clap,
+            RawFeature::Compact { .. } => FeatureKind::Compact,
+            RawFeature::Dispatch { ref value, .. } => {
+                match DispatchKind::from_name(&value.value()) {
+                    Some(dispatch) => FeatureKind::Dispatch(dispatch),
+                    None => {
+                        return Err(Error::new_spanned(
+                            value,
+                            format!(
+                            "Unexpected value for #[sternum(dispatch = ...)]; expected one of {}",
+                            DispatchKind::names(),
+                        ),
+                        ))
+                    }
+                }
+            }
+            RawFeature::FindIter { .. } => FeatureKind::FindIter,
+            RawFeature::Flatten { .. } => FeatureKind::Flatten,
+            RawFeature::Loose { .. } => FeatureKind::Loose,
+            RawFeature::Nested { .. } => FeatureKind::Nested,
+            RawFeature::Normalize { ref value, .. } => FeatureKind::Normalize(value.clone()),
+            RawFeature::Other { .. } => FeatureKind::Other,
+            RawFeature::Pattern { ref value, .. } => FeatureKind::Pattern(value.clone()),
+            RawFeature::Rename { ref value, .. } => FeatureKind::Rename(value.clone()),
+            RawFeature::RenameAll { ref value, .. } => {
+                match TransformKind::from_rename_all_name(&value.value()) {
+                    Some(trans) => FeatureKind::Transform(trans),
+                    None => {
+                        return Err(Error::new_spanned(
+                            value,
+                            format!(
+                            "Unexpected value for #[sternum(rename_all = ...)]; expected one of {}",
+                            TransformKind::rename_all_names(),
+                        ),
+                        ))
+                    }
+                }
+            }
             RawFeature::Scoped { .. } => FeatureKind::Scoped,
+            RawFeature::Separator { ref value, .. } => FeatureKind::Separator(value.clone()),
+            RawFeature::Serde { .. } => FeatureKind::Serde,
             RawFeature::Transform { ref value, .. } => {
-                let trans = match &*value.to_string() {
-                    "uppercase" => TransformKind::Uppercase,
-                    "lowercase" => TransformKind::Lowercase,
-                    _ => return Err(Error::new_spanned(
-                        value,
-                        "Unexpected value for #[sternum(transform = ...)]; expected `uppercase' or `lowercase'")),
-                };
-
-                FeatureKind::Transform(trans)
+                match TransformKind::from_transform_name(&value.to_string()) {
+                    Some(trans) => FeatureKind::Transform(trans),
+                    None => {
+                        return Err(Error::new_spanned(
+                            value,
+                            format!(
+                            "Unexpected value for #[sternum(transform = ...)]; expected one of {}",
+                            TransformKind::transform_names(),
+                        ),
+                        ))
+                    }
+                }
             }
+            RawFeature::Trim { .. } => FeatureKind::Trim,
         };
 
         Ok(Feature { kind, raw })
@@ -74,36 +218,317 @@ impl TryFrom<RawFeature> for Feature {
 /// [FeatureSet]: struct.FeatureSet.html
 #[derive(Debug, Eq, PartialEq)]
 enum FeatureKind {
+    Abbrev,
+    Alias(syn::LitStr),
     CaseInsensitive,
+    Clap,
+    Compact,
+    Dispatch(DispatchKind),
+    FindIter,
+    Flatten,
+    Loose,
+    Nested,
+    Normalize(syn::Path),
+    Other,
+    Pattern(syn::LitStr),
+    Rename(syn::LitStr),
     Scoped,
+    Separator(syn::LitStr),
+    Serde,
     Transform(TransformKind),
+    Trim,
 }
 
-impl FeatureSet {
-    /// Attempt to apply the feature to the `FeatureSet`.
+/// A set of features that [`Feature`s][Feature] can be applied to.
+///
+/// [Feature]: struct.Feature.html
+trait ApplyFeature {
+    /// Attempt to apply the feature to the set.
     ///
-    /// An error indicates that the provided feature conflicts with the current set of features.
+    /// An error indicates that the provided feature conflicts with the current set of features or
+    /// is not supported in this position.
+    fn apply(&mut self, f: Feature) -> Result<(), Error>;
+}
+
+impl ApplyFeature for FeatureSet {
     fn apply(&mut self, f: Feature) -> Result<(), Error> {
         use FeatureKind::*;
 
         match f.kind {
+            Abbrev => {
+                if self.loose {
+                    return Err(Error::new_spanned(
+                        f.raw,
+                        "#[sternum(abbrev)] cannot be combined with #[sternum(loose)]",
+                    ));
+                }
+
+                if self.normalize.is_some() {
+                    return Err(Error::new_spanned(
+                        f.raw,
+                        "#[sternum(abbrev)] cannot be combined with #[sternum(normalize = ...)]",
+                    ));
+                }
+
+                self.abbrev = true;
+            }
+
             CaseInsensitive => {
                 self.case_insensitive = true;
             }
 
+            Clap => {
+                if !cfg!(feature = "clap") {
+                    return Err(Error::new_spanned(
+                        f.raw,
+                        "#[sternum(clap)] requires the `clap' feature of sternum",
+                    ));
+                }
+
+                self.clap = true;
+            }
+
+            Compact => {
+                if self.dispatch.is_some() {
+                    return Err(Error::new_spanned(
+                        f.raw,
+                        "#[sternum(compact)] cannot be combined with #[sternum(dispatch = ...)]",
+                    ));
+                }
+
+                self.compact = true;
+            }
+
+            Dispatch(..) if self.compact => {
+                return Err(Error::new_spanned(
+                    f.raw,
+                    "#[sternum(dispatch = ...)] cannot be combined with #[sternum(compact)]",
+                ));
+            }
+
+            Dispatch(..) if self.loose => {
+                return Err(Error::new_spanned(
+                    f.raw,
+                    "#[sternum(dispatch = ...)] cannot be combined with #[sternum(loose)]",
+                ));
+            }
+
+            Dispatch(..) if self.normalize.is_some() => {
+                return Err(Error::new_spanned(
+                    f.raw,
+                    "#[sternum(dispatch = ...)] cannot be combined with #[sternum(normalize = ...)]",
+                ));
+            }
+
+            Dispatch(dispatch) => match self.dispatch {
+                Some(prev_dispatch) => {
+                    if prev_dispatch != dispatch {
+                        return Err(Error::new_spanned(f.raw, "Repeated"));
+                    }
+                }
+
+                None => self.dispatch = Some(dispatch),
+            },
+
+            Loose => {
+                if self.dispatch.is_some() {
+                    return Err(Error::new_spanned(
+                        f.raw,
+                        "#[sternum(loose)] cannot be combined with #[sternum(dispatch = ...)]",
+                    ));
+                }
+
+                if self.normalize.is_some() {
+                    return Err(Error::new_spanned(
+                        f.raw,
+                        "#[sternum(loose)] cannot be combined with #[sternum(normalize = ...)]",
+                    ));
+                }
+
+                if self.abbrev {
+                    return Err(Error::new_spanned(
+                        f.raw,
+                        "#[sternum(loose)] cannot be combined with #[sternum(abbrev)]",
+                    ));
+                }
+
+                self.loose = true;
+            }
+
+            Normalize(..) if self.dispatch.is_some() => {
+                return Err(Error::new_spanned(
+                    f.raw,
+                    "#[sternum(normalize = ...)] cannot be combined with #[sternum(dispatch = ...)]",
+                ));
+            }
+
+            Normalize(..) if self.loose => {
+                return Err(Error::new_spanned(
+                    f.raw,
+                    "#[sternum(normalize = ...)] cannot be combined with #[sternum(loose)]",
+                ));
+            }
+
+            Normalize(..) if self.abbrev => {
+                return Err(Error::new_spanned(
+                    f.raw,
+                    "#[sternum(normalize = ...)] cannot be combined with #[sternum(abbrev)]",
+                ));
+            }
+
+            Normalize(..) if !cfg!(feature = "alloc") => {
+                return Err(Error::new_spanned(
+                    f.raw,
+                    "#[sternum(normalize = ...)] requires the `alloc' feature of sternum",
+                ));
+            }
+
+            Normalize(normalize) => match self.normalize {
+                Some(ref prev_normalize) => {
+                    if *prev_normalize != normalize {
+                        return Err(Error::new_spanned(f.raw, "Repeated"));
+                    }
+                }
+
+                None => self.normalize = Some(normalize),
+            },
+
             Scoped => {
                 self.scoped = true;
             }
 
-            Transform(trans) => match &self.transform {
+            Serde => {
+                if !cfg!(feature = "serde") {
+                    return Err(Error::new_spanned(
+                        f.raw,
+                        "#[sternum(serde)] requires the `serde' feature of sternum",
+                    ));
+                }
+
+                self.serde = true;
+            }
+
+            Trim => {
+                self.trim = true;
+            }
+
+            Transform(trans) => match self.transform {
                 Some(prev_trans) => {
-                    if *prev_trans != trans {
+                    if prev_trans != trans {
                         return Err(Error::new_spanned(f.raw, "Repeated"));
                     }
                 }
 
                 None => self.transform = Some(trans),
             },
+
+            Alias(..) => {
+                return Err(Error::new_spanned(
+                    f.raw,
+                    "#[sternum(alias = ...)] is only supported on enum variants",
+                ));
+            }
+
+            Separator(separator) => match &self.separator {
+                Some(prev_separator) => {
+                    if *prev_separator != separator {
+                        return Err(Error::new_spanned(f.raw, "Repeated"));
+                    }
+                }
+
+                None => self.separator = Some(separator),
+            },
+
+            FindIter => {
+                self.find_iter = true;
+            }
+
+            Flatten => {
+                return Err(Error::new_spanned(
+                    f.raw,
+                    "#[sternum(flatten)] is only supported on enum variants",
+                ));
+            }
+
+            Nested => {
+                return Err(Error::new_spanned(
+                    f.raw,
+                    "#[sternum(nested)] is only supported on enum variants",
+                ));
+            }
+
+            Other => {
+                return Err(Error::new_spanned(
+                    f.raw,
+                    "#[sternum(other)] is only supported on enum variants",
+                ));
+            }
+
+            Pattern(..) => {
+                return Err(Error::new_spanned(
+                    f.raw,
+                    "#[sternum(pattern = ...)] is only supported on enum variants",
+                ));
+            }
+
+            Rename(..) => {
+                return Err(Error::new_spanned(
+                    f.raw,
+                    "#[sternum(rename = ...)] is only supported on enum variants",
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl ApplyFeature for VariantFeatureSet {
+    fn apply(&mut self, f: Feature) -> Result<(), Error> {
+        use FeatureKind::*;
+
+        match f.kind {
+            Alias(alias) => self.aliases.push(alias),
+
+            Flatten => {
+                self.flatten = true;
+            }
+
+            Nested => {
+                self.nested = true;
+            }
+
+            Other => {
+                self.other = true;
+            }
+
+            Pattern(pattern) => match &self.pattern {
+                Some(prev_pattern) => {
+                    if *prev_pattern != pattern {
+                        return Err(Error::new_spanned(f.raw, "Repeated"));
+                    }
+                }
+
+                None => self.pattern = Some(pattern),
+            },
+
+            Rename(name) => match &self.rename {
+                Some(prev_name) => {
+                    if *prev_name != name {
+                        return Err(Error::new_spanned(f.raw, "Repeated"));
+                    }
+                }
+
+                None => self.rename = Some(name),
+            },
+
+            Abbrev | CaseInsensitive | Clap | Compact | Dispatch(..) | FindIter | Loose
+            | Normalize(..) | Scoped | Separator(..) | Serde | Transform(..) | Trim => {
+                return Err(Error::new_spanned(
+                    f.raw,
+                    "This attribute is only supported on the enum itself, not its variants",
+                ));
+            }
         }
 
         Ok(())
@@ -112,21 +537,21 @@ impl FeatureSet {
 
 /// The current parsing state over the iterator of `Feature`s in `parse_features`.
 #[derive(Debug, Default)]
-struct ParseState {
+struct ParseState<S> {
     /// The accumulated errors, either from earlier in parsing or from calling
     /// [`FeatureSet::apply`][FeatureSet::apply] on incoming [`Feature`s][Feature].
     ///
     /// [Feature]: struct.Feature.html
     /// [FeatureSet::apply]: struct.FeatureSet.html#method.apply
     errors: Vec<Error>,
-    features: FeatureSet,
+    features: S,
 }
 
-impl ParseState {
+impl<S> ParseState<S> {
     /// Finalize the ParseState into a set of Features (if we have no errors) or the accumulated
     /// errors.
-    fn finalize(self) -> Result<FeatureSet, ErrorList> {
-        if self.errors.len() == 0 {
+    fn finalize(self) -> Result<S, ErrorList> {
+        if self.errors.is_empty() {
             Ok(self.features)
         } else {
             Err(ErrorList(self.errors))
@@ -134,11 +559,26 @@ impl ParseState {
     }
 }
 
-/// Attempt to parse the arguments to all `#[sternum(...)]` attributes into a
+/// Attempt to parse the arguments to all `#[sternum(...)]` attributes on the enum into a
 /// [`FeatureSet`][FeatureSet].
 ///
 /// [FeatureSet]: struct.FeatureSet.html
 pub fn parse_features(attrs: &[syn::Attribute]) -> Result<FeatureSet, ErrorList> {
+    parse_attrs(attrs)
+}
+
+/// Attempt to parse the arguments to all `#[sternum(...)]` attributes on a variant into a
+/// [`VariantFeatureSet`][VariantFeatureSet].
+///
+/// [VariantFeatureSet]: struct.VariantFeatureSet.html
+pub fn parse_variant_features(attrs: &[syn::Attribute]) -> Result<VariantFeatureSet, ErrorList> {
+    parse_attrs(attrs)
+}
+
+fn parse_attrs<S>(attrs: &[syn::Attribute]) -> Result<S, ErrorList>
+where
+    S: ApplyFeature + Default,
+{
     attrs
         .iter()
         .filter(|attr| attr.path.is_ident("sternum"))
@@ -152,7 +592,7 @@ pub fn parse_features(attrs: &[syn::Attribute]) -> Result<FeatureSet, ErrorList>
                 Err(e) => Either::Right(std::iter::once(Err(e))),
             }
         })
-        .fold(ParseState::default(), |mut state, item| {
+        .fold(ParseState::<S>::default(), |mut state, item| {
             // We don't .collect() here so that we can return as much error information as possible
             // to the user.
             match item {
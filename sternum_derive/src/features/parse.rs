@@ -10,24 +10,89 @@ use proc_macro2::TokenStream;
 use quote::ToTokens;
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
-use syn::{Error, Ident, Token};
+use syn::{Error, Ident, LitStr, Path, Token};
 
 /// A raw feature, parseable from a [`TokenStream`][TokenStream].
 ///
 /// [TokenStream]: ../proc-macro2/struct.TokenStream.html
 #[derive(Debug, Eq, PartialEq)]
 pub(super) enum RawFeature {
+    Abbrev {
+        ident: Ident,
+    },
+    Alias {
+        ident: Ident,
+        eq: Token![=],
+        value: LitStr,
+    },
     CaseInsensitive {
         ident: Ident,
     },
+    Clap {
+        ident: Ident,
+    },
+    Compact {
+        ident: Ident,
+    },
+    Dispatch {
+        ident: Ident,
+        eq: Token![=],
+        value: LitStr,
+    },
+    FindIter {
+        ident: Ident,
+    },
+    Flatten {
+        ident: Ident,
+    },
+    Loose {
+        ident: Ident,
+    },
+    Nested {
+        ident: Ident,
+    },
+    Normalize {
+        ident: Ident,
+        eq: Token![=],
+        value: Path,
+    },
+    Other {
+        ident: Ident,
+    },
+    Pattern {
+        ident: Ident,
+        eq: Token![=],
+        value: LitStr,
+    },
+    Rename {
+        ident: Ident,
+        eq: Token![=],
+        value: LitStr,
+    },
+    RenameAll {
+        ident: Ident,
+        eq: Token![=],
+        value: LitStr,
+    },
     Scoped {
         ident: Ident,
     },
+    Separator {
+        ident: Ident,
+        eq: Token![=],
+        value: LitStr,
+    },
+    Serde {
+        ident: Ident,
+    },
     Transform {
         ident: Ident,
         eq: Token![=],
         value: Ident,
     },
+    Trim {
+        ident: Ident,
+    },
 }
 
 /// The comma-separated list of tokens that make up the arguments to the `#[sternum(...)]`
@@ -46,8 +111,80 @@ impl ToTokens for RawFeature {
         use RawFeature::*;
 
         match self {
+            Abbrev { ref ident } => ident.to_tokens(tokens),
+            Alias {
+                ref ident,
+                ref eq,
+                ref value,
+            } => {
+                ident.to_tokens(tokens);
+                eq.to_tokens(tokens);
+                value.to_tokens(tokens);
+            }
             CaseInsensitive { ref ident } => ident.to_tokens(tokens),
+            Clap { ref ident } => ident.to_tokens(tokens),
+            Compact { ref ident } => ident.to_tokens(tokens),
+            Dispatch {
+                ref ident,
+                ref eq,
+                ref value,
+            } => {
+                ident.to_tokens(tokens);
+                eq.to_tokens(tokens);
+                value.to_tokens(tokens);
+            }
+            FindIter { ref ident } => ident.to_tokens(tokens),
+            Flatten { ref ident } => ident.to_tokens(tokens),
+            Loose { ref ident } => ident.to_tokens(tokens),
+            Nested { ref ident } => ident.to_tokens(tokens),
+            Normalize {
+                ref ident,
+                ref eq,
+                ref value,
+            } => {
+                ident.to_tokens(tokens);
+                eq.to_tokens(tokens);
+                value.to_tokens(tokens);
+            }
+            Other { ref ident } => ident.to_tokens(tokens),
+            Pattern {
+                ref ident,
+                ref eq,
+                ref value,
+            } => {
+                ident.to_tokens(tokens);
+                eq.to_tokens(tokens);
+                value.to_tokens(tokens);
+            }
+            Rename {
+                ref ident,
+                ref eq,
+                ref value,
+            } => {
+                ident.to_tokens(tokens);
+                eq.to_tokens(tokens);
+                value.to_tokens(tokens);
+            }
+            RenameAll {
+                ref ident,
+                ref eq,
+                ref value,
+            } => {
+                ident.to_tokens(tokens);
+                eq.to_tokens(tokens);
+                value.to_tokens(tokens);
+            }
             Scoped { ref ident } => ident.to_tokens(tokens),
+            Separator {
+                ref ident,
+                ref eq,
+                ref value,
+            } => {
+                ident.to_tokens(tokens);
+                eq.to_tokens(tokens);
+                value.to_tokens(tokens);
+            }
+            Serde { ref ident } => ident.to_tokens(tokens),
             Transform {
                 ref ident,
                 ref eq,
@@ -57,6 +194,7 @@ impl ToTokens for RawFeature {
                 eq.to_tokens(tokens);
                 value.to_tokens(tokens);
             }
+            Trim { ref ident } => ident.to_tokens(tokens),
         }
     }
 }
@@ -69,16 +207,78 @@ impl Parse for RawFeature {
         let ident_name = ident.to_string();
 
         let feature = match &*ident_name {
+            "abbrev" => Abbrev { ident },
+
+            "alias" => Alias {
+                ident,
+                eq: input.parse()?,
+                value: input.parse()?,
+            },
+
             "case_insensitive" => CaseInsensitive { ident },
 
+            "clap" => Clap { ident },
+
+            "compact" => Compact { ident },
+
+            "dispatch" => Dispatch {
+                ident,
+                eq: input.parse()?,
+                value: input.parse()?,
+            },
+
+            "find_iter" => FindIter { ident },
+
+            "flatten" => Flatten { ident },
+
+            "loose" => Loose { ident },
+
+            "nested" => Nested { ident },
+
+            "normalize" => Normalize {
+                ident,
+                eq: input.parse()?,
+                value: input.parse()?,
+            },
+
+            "other" => Other { ident },
+
+            "pattern" => Pattern {
+                ident,
+                eq: input.parse()?,
+                value: input.parse()?,
+            },
+
+            "rename" => Rename {
+                ident,
+                eq: input.parse()?,
+                value: input.parse()?,
+            },
+
+            "rename_all" => RenameAll {
+                ident,
+                eq: input.parse()?,
+                value: input.parse()?,
+            },
+
             "scoped" => Scoped { ident },
 
+            "separator" => Separator {
+                ident,
+                eq: input.parse()?,
+                value: input.parse()?,
+            },
+
+            "serde" => Serde { ident },
+
             "transform" => Transform {
                 ident,
                 eq: input.parse()?,
                 value: input.parse()?,
             },
 
+            "trim" => Trim { ident },
+
             _ => {
                 return Err(Error::new_spanned(
                     ident,
@@ -0,0 +1,156 @@
+// Copyright 2019 Barret Rennie
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::features::TransformKind;
+
+/// The transforms, along with their names for `#[sternum(transform = ...)]` and
+/// `#[sternum(rename_all = "...")]`, respectively.
+const TRANSFORMS: &[(TransformKind, &str, &str)] = &[
+    (TransformKind::Uppercase, "uppercase", "UPPERCASE"),
+    (TransformKind::Lowercase, "lowercase", "lowercase"),
+    (TransformKind::SnakeCase, "snake_case", "snake_case"),
+    (TransformKind::KebabCase, "kebab_case", "kebab-case"),
+    (
+        TransformKind::ScreamingSnakeCase,
+        "screaming_snake_case",
+        "SCREAMING_SNAKE_CASE",
+    ),
+    (
+        TransformKind::ScreamingKebabCase,
+        "screaming_kebab_case",
+        "SCREAMING-KEBAB-CASE",
+    ),
+    (TransformKind::CamelCase, "camel_case", "camelCase"),
+    (TransformKind::PascalCase, "pascal_case", "PascalCase"),
+    (TransformKind::TrainCase, "train_case", "Train-Case"),
+    (TransformKind::DotCase, "dot_case", "dot.case"),
+    (TransformKind::TitleCase, "title_case", "Title Case"),
+];
+
+impl TransformKind {
+    /// Look up a transform by its `#[sternum(transform = ...)]` name.
+    pub fn from_transform_name(name: &str) -> Option<Self> {
+        TRANSFORMS
+            .iter()
+            .find(|(_, transform_name, _)| *transform_name == name)
+            .map(|(kind, _, _)| *kind)
+    }
+
+    /// Look up a transform by its `#[sternum(rename_all = "...")]` name.
+    pub fn from_rename_all_name(name: &str) -> Option<Self> {
+        TRANSFORMS
+            .iter()
+            .find(|(_, _, rename_all_name)| *rename_all_name == name)
+            .map(|(kind, _, _)| *kind)
+    }
+
+    /// A human-readable list of the valid `#[sternum(transform = ...)]` names.
+    pub fn transform_names() -> String {
+        quoted_list(TRANSFORMS.iter().map(|(_, name, _)| *name))
+    }
+
+    /// A human-readable list of the valid `#[sternum(rename_all = "...")]` names.
+    pub fn rename_all_names() -> String {
+        quoted_list(TRANSFORMS.iter().map(|(_, _, name)| *name))
+    }
+
+    /// Apply the transform to an identifier.
+    pub fn apply(self, ident: &str) -> String {
+        use TransformKind::*;
+
+        match self {
+            Uppercase => ident.to_uppercase(),
+            Lowercase => ident.to_lowercase(),
+            SnakeCase => join_words(ident, "_", str::to_lowercase),
+            KebabCase => join_words(ident, "-", str::to_lowercase),
+            ScreamingSnakeCase => join_words(ident, "_", str::to_uppercase),
+            ScreamingKebabCase => join_words(ident, "-", str::to_uppercase),
+            CamelCase => {
+                let mut words = split_words(ident).into_iter();
+                let mut transformed = words.next().map(str::to_lowercase).unwrap_or_default();
+                transformed.extend(words.map(capitalize));
+                transformed
+            }
+            PascalCase => join_words(ident, "", capitalize),
+            TrainCase => join_words(ident, "-", capitalize),
+            DotCase => join_words(ident, ".", str::to_lowercase),
+            TitleCase => join_words(ident, " ", capitalize),
+        }
+    }
+}
+
+fn quoted_list<'a, I>(names: I) -> String
+where
+    I: Iterator<Item = &'a str>,
+{
+    names
+        .map(|name| format!("`{}'", name))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Split an identifier into its words, transform each word, and join them with a separator.
+fn join_words<F>(ident: &str, sep: &str, f: F) -> String
+where
+    F: Fn(&str) -> String,
+{
+    split_words(ident)
+        .into_iter()
+        .map(f)
+        .collect::<Vec<_>>()
+        .join(sep)
+}
+
+/// Uppercase the first character of a word and lowercase the rest.
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+/// Split an identifier into words.
+///
+/// Words are separated by underscores and at changes in case: a lowercase letter or digit followed
+/// by an uppercase letter starts a new word (`LoudNoises` and `Utf8Error`), as does the last
+/// uppercase letter of an acronym that is followed by a lowercase letter (`HTTPSProxy`). Digits
+/// otherwise belong to the word that they follow.
+fn split_words(ident: &str) -> Vec<&str> {
+    let mut words = vec![];
+
+    for part in ident.split(|c: char| !c.is_alphanumeric()) {
+        let chars: Vec<(usize, char)> = part.char_indices().collect();
+        let mut start = 0;
+
+        for (i, &(idx, c)) in chars.iter().enumerate().skip(1) {
+            let prev = chars[i - 1].1;
+            let next = chars.get(i + 1).map(|&(_, next)| next);
+
+            let boundary = c.is_uppercase()
+                && (prev.is_lowercase()
+                    || prev.is_numeric()
+                    || (prev.is_uppercase() && next.is_some_and(char::is_lowercase)));
+
+            if boundary {
+                words.push(&part[start..idx]);
+                start = idx;
+            }
+        }
+
+        if !part.is_empty() {
+            words.push(&part[start..]);
+        }
+    }
+
+    words
+}
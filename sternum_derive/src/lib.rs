@@ -8,20 +8,26 @@
 
 #![recursion_limit = "128"]
 
+mod dispatch;
 mod error;
 mod features;
+mod fold;
+mod pattern;
+mod search;
+mod variant;
 
 extern crate proc_macro;
 
 use std::collections::HashMap;
-use std::convert::identity;
 
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote, quote_spanned};
 use syn::{parse_macro_input, DeriveInput, Error, Ident};
 
+use crate::dispatch::Entry;
 use crate::error::ErrorList;
-use crate::features::{parse_features, FeatureSet, TransformKind};
+use crate::features::{parse_features, FeatureSet};
+use crate::variant::{collect_variants, Variant, VariantKind};
 
 /// The custom derive for the [`Sternum`][sternum::Sternum] trait.
 ///
@@ -49,7 +55,7 @@ fn derive_impl(ast: &DeriveInput) -> Result<TokenStream, ErrorList> {
         }
     };
 
-    if variants.len() == 0 {
+    if variants.is_empty() {
         return Err(Error::new_spanned(
             ast,
             "Sternum only supports enums with at least one variant",
@@ -57,69 +63,152 @@ fn derive_impl(ast: &DeriveInput) -> Result<TokenStream, ErrorList> {
         .into());
     }
 
-    {
-        let variant_errors: Vec<Error> = variants
+    let features = parse_features(&ast.attrs)?;
+
+    if features.compact {
+        let compact_errors: Vec<Error> = variants
             .iter()
-            .filter_map(|variant| match variant.fields {
-                syn::Fields::Unit => None,
-                syn::Fields::Named(..) | syn::Fields::Unnamed(..) => Some(Error::new_spanned(
-                    variant,
-                    "Sternum only supports unit enum variants (like Option::None)",
-                )),
+            .filter_map(|variant| {
+                if !matches!(variant.fields, syn::Fields::Unit) {
+                    Some(Error::new_spanned(
+                        variant,
+                        "#[sternum(compact)] only supports unit enum variants",
+                    ))
+                } else if let Some((_, ref discriminant)) = variant.discriminant {
+                    Some(Error::new_spanned(
+                        discriminant,
+                        "#[sternum(compact)] does not support explicit discriminants",
+                    ))
+                } else {
+                    None
+                }
             })
             .collect();
 
-        if variant_errors.len() != 0 {
-            return Err(ErrorList(variant_errors));
+        if !compact_errors.is_empty() {
+            return Err(ErrorList(compact_errors));
         }
     }
 
-    let features = parse_features(&ast.attrs)?;
+    let variants = collect_variants(&ast.ident, variants, &features)?;
 
-    if features.case_insensitive || features.transform.is_some() {
+    {
         let variant_errors: Vec<Error> = variants
             .iter()
-            .scan(HashMap::<String, &Ident>::new(), |variant_names, variant| {
-                let name = variant.ident.to_string().to_lowercase();
-
-                if let Some(ref prev_ident) = variant_names.get(&name) {
-                    Some(Some(Error::new_spanned(
-                        &variant.ident,
-                        format!("The variant `{}' is a case-insensitive match of a previous identifier (`{}')",
-                            variant.ident.to_string(),
-                            prev_ident.to_string(),
-                        ))))
-                } else {
-                    variant_names.insert(name, &variant.ident);
-                    Some(None)
-                }
-            })
-            .filter_map(identity)
+            .flat_map(|variant| variant.names().map(move |name| (variant, name)))
+            .scan(
+                HashMap::<String, &Variant>::new(),
+                |variant_names, (variant, name)| {
+                    let key = if features.loose {
+                        normalize_loose(&name.value)
+                    } else if features.case_insensitive {
+                        fold_case(&name.value)
+                    } else {
+                        name.value.clone()
+                    };
+
+                    if let Some(prev_variant) = variant_names.get(&key) {
+                        let subject = if name.alias {
+                            format!("The alias `{}' of variant `{}'", name.value, variant.ident)
+                        } else {
+                            format!("The variant `{}'", variant.ident)
+                        };
+
+                        let message = if features.loose {
+                            format!(
+                                "{} is a loose match of a previous identifier (`{}')",
+                                subject, prev_variant.ident,
+                            )
+                        } else if features.case_insensitive {
+                            format!(
+                                "{} is a case-insensitive match of a previous identifier (`{}')",
+                                subject, prev_variant.ident,
+                            )
+                        } else {
+                            format!(
+                                "{} has the same name (`{}') as a previous identifier (`{}')",
+                                subject, name.value, prev_variant.ident,
+                            )
+                        };
+
+                        Some(Some(Error::new_spanned(name.tokens, message)))
+                    } else {
+                        variant_names.insert(key, variant);
+                        Some(None)
+                    }
+                },
+            )
+            .flatten()
             .collect();
 
-        if variant_errors.len() != 0 {
+        if !variant_errors.is_empty() {
             return Err(ErrorList(variant_errors));
         }
     }
 
-    let sternum_impl = impl_sternum(&ast.ident);
-    let display_impl = impl_display(&ast.ident, variants.iter(), &features);
-    let from_str_impl = impl_from_str(&ast.ident, variants.iter(), &features);
+    let sternum_impl = impl_sternum(&ast.ident, &variants);
+    let display_impl = impl_display(&ast.ident, &variants, &features);
+    let as_str_impl = impl_as_str(&ast.ident, &variants, &features);
+    let complete_impl = impl_complete(&ast.ident, &variants, &features);
+    let parse_prefix_impl = impl_parse_prefix(&ast.ident, &variants, &features);
+    let find_iter_impl = if features.find_iter {
+        impl_find_iter(&ast.ident, &variants, &features)
+    } else {
+        TokenStream::new()
+    };
+    let from_str_impl = impl_from_str(&ast.ident, &variants, &features);
+    let try_from_impl = impl_try_from(&ast.ident);
+    let serde_impl = if features.serde {
+        impl_serde(&ast.ident, &variants)
+    } else {
+        TokenStream::new()
+    };
+    let clap_impl = if features.clap {
+        impl_clap(&ast.ident, &variants)
+    } else {
+        TokenStream::new()
+    };
+    let abbrev_impl = if features.abbrev {
+        impl_abbrev(&ast.ident, &variants, &features)?
+    } else {
+        TokenStream::new()
+    };
 
     let quoted = quote! {
         #sternum_impl
         #display_impl
+        #as_str_impl
+        #complete_impl
+        #parse_prefix_impl
+        #find_iter_impl
         #from_str_impl
+        #try_from_impl
+        #serde_impl
+        #clap_impl
+        #abbrev_impl
     };
 
-    Ok(quoted.into())
+    Ok(quoted)
 }
 
-fn impl_sternum(type_name: &syn::Ident) -> TokenStream {
+fn impl_sternum(type_name: &Ident, variants: &[Variant]) -> TokenStream {
     let type_name_as_str = type_name.to_string();
 
+    let (idents, names): (Vec<_>, Vec<_>) = variants
+        .iter()
+        .filter_map(|variant| match variant.kind {
+            VariantKind::Unit { ref name } => Some((variant.ident, name)),
+            _ => None,
+        })
+        .unzip();
+    let unit_only = idents.len() == variants.len();
+
     quote! {
         impl ::sternum::Sternum for #type_name {
+            const VARIANTS: &'static [Self] = &[#(#type_name::#idents),*];
+            const NAMES: &'static [&'static str] = &[#(#names),*];
+            const UNIT_ONLY: bool = #unit_only;
+
             fn type_name() -> &'static str {
                 return #type_name_as_str;
             }
@@ -128,38 +217,61 @@ fn impl_sternum(type_name: &syn::Ident) -> TokenStream {
     }
 }
 
-fn impl_display<'a, I>(type_name: &syn::Ident, variants: I, features: &FeatureSet) -> TokenStream
-where
-    I: Iterator<Item = &'a syn::Variant>,
-{
-    let matches = variants.map(|variant| {
-        let ident = &variant.ident;
+fn impl_display(type_name: &Ident, variants: &[Variant], features: &FeatureSet) -> TokenStream {
+    if features.compact {
+        let index = compact_index(type_name);
 
-        let repr = if features.scoped {
-            format!("{}::{}", type_name, ident)
-        } else {
-            ident.to_string()
+        return quote! {
+            impl ::core::fmt::Display for #type_name {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    f.write_str(<Self as ::sternum::Sternum>::NAMES[#index])
+                }
+            }
         };
+    }
+
+    let separator = features.separator();
+
+    let matches = variants.iter().map(|variant| {
+        let ident = variant.ident;
 
-        let repr = if let Some(ref trans) = &features.transform {
-            match trans {
-                TransformKind::Uppercase => repr.to_uppercase(),
-                TransformKind::Lowercase => repr.to_lowercase(),
+        match variant.kind {
+            VariantKind::Unit { ref name } => {
+                let repr: syn::Lit = syn::LitStr::new(name, ident.span()).into();
+
+                quote! {
+                    #type_name::#ident => f.write_str(#repr),
+                }
             }
-        } else {
-            repr
-        };
 
-        let repr: syn::Lit = syn::LitStr::new(&repr, ident.span()).into();
+            VariantKind::Other => quote! {
+                #type_name::#ident(value) => {
+                    f.write_str(::core::convert::AsRef::<str>::as_ref(value))
+                }
+            },
 
-        quote! {
-            #type_name::#ident => write!(f, #repr),
+            VariantKind::Pattern(ref pattern) => pattern.display_arm(type_name, ident),
+
+            VariantKind::Nested { ref prefix, .. } => {
+                let prefix = format!("{}{}", prefix, separator);
+
+                quote! {
+                    #type_name::#ident(value) => {
+                        f.write_str(#prefix)?;
+                        ::core::fmt::Display::fmt(value, f)
+                    }
+                }
+            }
+
+            VariantKind::Flatten { .. } => quote! {
+                #type_name::#ident(value) => ::core::fmt::Display::fmt(value, f),
+            },
         }
     });
 
     quote! {
-        impl ::std::fmt::Display for #type_name {
-            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        impl ::core::fmt::Display for #type_name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
                 match self {
                     #(#matches)*
                 }
@@ -168,45 +280,808 @@ where
     }
 }
 
-fn impl_from_str<'a, I>(type_name: &syn::Ident, variants: I, features: &FeatureSet) -> TokenStream
-where
-    I: Iterator<Item = &'a syn::Variant>,
-{
-    let matches = variants.map(|variant| {
-        let ident = &variant.ident;
-        let repr = if features.scoped {
-            format!("{}::{}", type_name, ident)
-        } else {
-            ident.to_string()
-        };
-
-        let repr = match (&features.case_insensitive, &features.transform) {
-            (true, _) | (false, Some(TransformKind::Lowercase)) => repr.to_lowercase(),
-            (false, Some(TransformKind::Uppercase)) => repr.to_uppercase(),
-            (false, None) => repr,
-        };
+/// Generate `as_str` and the conversions built on it.
+///
+/// These are only generated when every variant is a unit variant, since other variants do not
+/// have a `&'static str` representation.
+fn impl_as_str(type_name: &Ident, variants: &[Variant], features: &FeatureSet) -> TokenStream {
+    let matches: Option<Vec<TokenStream>> = variants
+        .iter()
+        .map(|variant| match variant.kind {
+            VariantKind::Unit { ref name } => {
+                let ident = variant.ident;
+                Some(quote! {
+                    #type_name::#ident => #name,
+                })
+            }
+            _ => None,
+        })
+        .collect();
 
-        let lit: syn::Lit = syn::LitStr::new(&repr, ident.span()).into();
+    let as_str = match matches {
+        Some(_) if features.compact => {
+            let index = compact_index(type_name);
+            quote! { <Self as ::sternum::Sternum>::NAMES[#index] }
+        }
+        Some(matches) => quote! {
+            match self {
+                #(#matches)*
+            }
+        },
+        None => return TokenStream::new(),
+    };
 
+    let alloc_impls = if cfg!(feature = "alloc") {
         quote! {
-            #lit => Ok(#type_name::#ident),
+            impl ::core::convert::From<#type_name> for ::sternum::__private::String {
+                fn from(value: #type_name) -> Self {
+                    ::sternum::__private::String::from(value.as_str())
+                }
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    quote! {
+        impl #type_name {
+            /// The name of the variant, as it is displayed.
+            pub const fn as_str(&self) -> &'static str {
+                #as_str
+            }
+        }
+
+        impl ::core::convert::AsRef<str> for #type_name {
+            fn as_ref(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        impl ::core::convert::From<#type_name> for &'static str {
+            fn from(value: #type_name) -> Self {
+                value.as_str()
+            }
+        }
+
+        #alloc_impls
+    }
+}
+
+/// Generate `complete`, which finds the unit variants whose names start with a prefix.
+///
+/// This is only generated when there are unit variants.
+fn impl_complete(type_name: &Ident, variants: &[Variant], features: &FeatureSet) -> TokenStream {
+    if !variants
+        .iter()
+        .any(|variant| matches!(variant.kind, VariantKind::Unit { .. }))
+    {
+        return TokenStream::new();
+    }
+
+    let variant = unit_variant(type_name, variants, features);
+    let case_insensitive = features.case_insensitive;
+
+    quote! {
+        impl #type_name {
+            /// Iterate over the unit variants whose names start with `prefix`, along with their
+            /// names, in declaration order.
+            pub fn complete(
+                prefix: &str,
+            ) -> impl ::core::iter::Iterator<Item = (Self, &'static str)> + '_ {
+                ::sternum::__private::complete(<Self as ::sternum::Sternum>::NAMES, prefix, #case_insensitive)
+                    .map(|i| (#variant, <Self as ::sternum::Sternum>::NAMES[i]))
+            }
+        }
+    }
+}
+
+/// Generate `parse_prefix`, which parses the longest name or alias of a unit variant at the
+/// start of a string.
+///
+/// This is only generated when there are unit variants.
+fn impl_parse_prefix(
+    type_name: &Ident,
+    variants: &[Variant],
+    features: &FeatureSet,
+) -> TokenStream {
+    let mut names: Vec<(String, usize)> = variants
+        .iter()
+        .filter(|variant| matches!(variant.kind, VariantKind::Unit { .. }))
+        .enumerate()
+        .flat_map(|(i, variant)| variant.names().map(move |name| (name.value, i)))
+        .map(|(name, i)| {
+            if features.case_insensitive {
+                (fold_case(&name), i)
+            } else {
+                (name, i)
+            }
+        })
+        .collect();
+
+    if names.is_empty() {
+        return TokenStream::new();
+    }
+
+    // The longest names come first so that the first name that matches is the longest one.
+    names.sort_by(|(a, _), (b, _)| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+
+    let names = names.iter().map(|(name, i)| quote! { (#name, #i) });
+    let variant = unit_variant(type_name, variants, features);
+    let case_insensitive = features.case_insensitive;
+
+    quote! {
+        impl #type_name {
+            /// Parse the longest name or alias of a unit variant at the start of `s`, returning
+            /// the variant and the rest of `s`.
+            pub fn parse_prefix(s: &str) -> ::core::option::Option<(Self, &str)> {
+                const NAMES: &[(&str, usize)] = &[#(#names),*];
+
+                ::sternum::__private::parse_prefix(NAMES, s, #case_insensitive)
+                    .map(|(i, rest)| (#variant, rest))
+            }
+        }
+    }
+}
+
+/// Generate `find_iter`, which finds the names of the unit variants in a string.
+///
+/// This is only generated when there are unit variants.
+fn impl_find_iter(type_name: &Ident, variants: &[Variant], features: &FeatureSet) -> TokenStream {
+    let keys: Vec<String> = variants
+        .iter()
+        .filter_map(|variant| match variant.kind {
+            VariantKind::Unit { ref name } if features.case_insensitive => Some(fold_case(name)),
+            VariantKind::Unit { ref name } => Some(name.clone()),
+            _ => None,
+        })
+        .collect();
+
+    if keys.is_empty() {
+        return TokenStream::new();
+    }
+
+    let automaton = search::automaton(&keys);
+    let variant = unit_variant(type_name, variants, features);
+    let case_insensitive = features.case_insensitive;
+
+    quote! {
+        impl #type_name {
+            /// Iterate over the non-overlapping occurrences of the names of the unit variants in
+            /// `haystack`, along with their ranges.
+            pub fn find_iter(
+                haystack: &str,
+            ) -> impl ::core::iter::Iterator<Item = (::core::ops::Range<usize>, Self)> + '_ {
+                #automaton
+
+                ::sternum::__private::FindIter::new(
+                    haystack,
+                    STATES,
+                    TRANSITIONS,
+                    #case_insensitive,
+                )
+                .map(|(range, i)| (range, #variant))
+            }
+        }
+    }
+}
+
+/// Generate an expression for the unit variant at index `i` of `Sternum::VARIANTS`.
+fn unit_variant(type_name: &Ident, variants: &[Variant], features: &FeatureSet) -> TokenStream {
+    if features.compact {
+        return compact_variant(type_name);
+    }
+
+    let arms = variants
+        .iter()
+        .filter(|variant| matches!(variant.kind, VariantKind::Unit { .. }))
+        .enumerate()
+        .map(|(i, variant)| {
+            let ident = variant.ident;
+            quote! { #i => #type_name::#ident, }
+        });
+
+    quote! {
+        match i {
+            #(#arms)*
+            _ => ::core::unreachable!(),
+        }
+    }
+}
+
+fn impl_from_str(type_name: &Ident, variants: &[Variant], features: &FeatureSet) -> TokenStream {
+    let fixed = if let Some(ref normalize) = features.normalize {
+        search_names_normalized(type_name, variants, features, normalize)
+    } else if features.loose {
+        search_names_loose(type_name, variants, features)
+    } else if features.compact {
+        search_names(type_name, variants, features)
+    } else if features.case_insensitive {
+        match_names_ignore_case(type_name, variants, features)
+    } else {
+        match_names(type_name, variants, features)
+    };
+
+    let other = variants
+        .iter()
+        .find(|variant| matches!(variant.kind, VariantKind::Other));
+
+    // Unless there is a catch-all variant, the error keeps why the first nested variant whose
+    // name the input starts with could not parse the rest of it.
+    let track_nested = other.is_none()
+        && variants
+            .iter()
+            .any(|variant| matches!(variant.kind, VariantKind::Nested { .. }));
+
+    // Variants with patterns, nested variants, and flattened variants are tried in declaration
+    // order after the fixed names. The first one that matches wins.
+    let dynamic = variants.iter().filter_map(|variant| {
+        let ident = variant.ident;
+
+        match variant.kind {
+            VariantKind::Pattern(ref pattern) => {
+                let fn_name = format_ident!("parse_{}", ident);
+                let parse_fn = pattern.parse_fn(&fn_name, type_name, ident);
+
+                Some(quote! {
+                    #parse_fn
+
+                    if let ::core::option::Option::Some(value) = #fn_name(s) {
+                        return Ok(value);
+                    }
+                })
+            }
+
+            VariantKind::Nested { ref prefix, ref ty } => {
+                let prefix = format!("{}{}", prefix, features.separator());
+                let strip_prefix = if features.case_insensitive {
+                    let prefix = fold_case(&prefix);
+                    quote! { ::sternum::__private::strip_prefix_ignore_case(s, #prefix) }
+                } else {
+                    quote! { s.strip_prefix(#prefix) }
+                };
+
+                let parse = if track_nested {
+                    quote! {
+                        match ::sternum::__private::parse_nested::<#ty>(rest) {
+                            Ok(value) => return Ok(#type_name::#ident(value)),
+                            Err(err) => {
+                                if nested.is_none() {
+                                    nested = ::sternum::__private::nested_error(input, rest, err);
+                                }
+                            }
+                        }
+                    }
+                } else {
+                    quote! {
+                        if let Ok(value) = ::sternum::__private::parse_nested::<#ty>(rest) {
+                            return Ok(#type_name::#ident(value));
+                        }
+                    }
+                };
+
+                Some(quote! {
+                    if let ::core::option::Option::Some(rest) = #strip_prefix {
+                        #parse
+                    }
+                })
+            }
+
+            VariantKind::Flatten { ref ty } => Some(quote! {
+                if let Ok(value) = ::sternum::__private::parse_nested::<#ty>(s) {
+                    return Ok(#type_name::#ident(value));
+                }
+            }),
+
+            VariantKind::Unit { .. } | VariantKind::Other => None,
         }
     });
 
-    let to_match = if features.case_insensitive {
-        quote! { s.to_lowercase() }
+    let fallback = match other {
+        Some(variant) => {
+            let ident = variant.ident;
+            quote! { Ok(#type_name::#ident(::core::convert::From::from(s))) }
+        }
+        None if track_nested => {
+            quote! { Err(::sternum::__private::unknown_variant(input, nested)) }
+        }
+        None => quote! { Err(::sternum::UnknownVariantError::new(input)) },
+    };
+
+    let nested = if track_nested {
+        quote! { let mut nested = ::core::option::Option::None; }
+    } else {
+        TokenStream::new()
+    };
+
+    let trim = if features.trim {
+        quote! { let s = s.trim(); }
     } else {
-        quote! { s }
+        TokenStream::new()
     };
 
     quote! {
-        impl ::std::str::FromStr for #type_name {
+        impl ::core::str::FromStr for #type_name {
             type Err = ::sternum::UnknownVariantError<#type_name>;
 
-            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
-                match &*#to_match {
+            fn from_str(
+                s: &str,
+            ) -> ::core::result::Result<Self, <Self as ::core::str::FromStr>::Err> {
+                let input = s;
+                #trim
+
+                #fixed
+
+                #nested
+                #(#dynamic)*
+
+                #fallback
+            }
+        }
+    }
+}
+
+/// Generate a lookup of the input in the names of the variants.
+fn match_names(type_name: &Ident, variants: &[Variant], features: &FeatureSet) -> TokenStream {
+    let entries: Vec<Entry> = variants
+        .iter()
+        .flat_map(|variant| {
+            let ident = variant.ident;
+
+            variant.names().map(move |name| Entry {
+                key: name.value,
+                then: quote! { return Ok(#type_name::#ident) },
+            })
+        })
+        .collect();
+
+    dispatch::lookup(features.dispatch(), &entries, false)
+}
+
+/// Generate case-insensitive comparisons of the input against the names of the variants that
+/// do not allocate.
+///
+/// ASCII input is looked up while ignoring ASCII case. Only names that are ASCII once case-folded
+/// can match ASCII input, so the rest are left out of that lookup. Any other input is compared
+/// character-by-character against each case-folded name.
+fn match_names_ignore_case(
+    type_name: &Ident,
+    variants: &[Variant],
+    features: &FeatureSet,
+) -> TokenStream {
+    let mut ascii = vec![];
+    let mut unicode = vec![];
+
+    for variant in variants {
+        let ident = variant.ident;
+
+        for name in variant.names() {
+            let folded = fold_case(&name.value);
+            let lit: syn::Lit = syn::LitStr::new(&folded, ident.span()).into();
+
+            unicode.push(quote! {
+                if ::sternum::__private::eq_ignore_case(s, #lit) {
+                    return Ok(#type_name::#ident);
+                }
+            });
+
+            if folded.is_ascii() {
+                ascii.push(Entry {
+                    key: folded,
+                    then: quote! { return Ok(#type_name::#ident) },
+                });
+            }
+        }
+    }
+
+    if unicode.is_empty() {
+        return TokenStream::new();
+    }
+
+    let ascii = dispatch::lookup(features.dispatch(), &ascii, true);
+
+    quote! {
+        if s.is_ascii() {
+            #ascii
+        } else {
+            #(#unicode)*
+        }
+    }
+}
+
+/// Generate a binary search of the input in a table of the names of the variants, sorted by
+/// their (case-folded, if case-insensitive) names.
+///
+/// This is only used for `#[sternum(compact)]` enums, whose variants are all unit variants and
+/// can be found by their index in `Sternum::VARIANTS`.
+fn search_names(type_name: &Ident, variants: &[Variant], features: &FeatureSet) -> TokenStream {
+    let mut table: Vec<(String, usize)> = variants
+        .iter()
+        .enumerate()
+        .flat_map(|(i, variant)| {
+            variant.names().map(move |name| {
+                if features.case_insensitive {
+                    (fold_case(&name.value), i)
+                } else {
+                    (name.value, i)
+                }
+            })
+        })
+        .collect();
+
+    table.sort();
+
+    let (keys, indices): (Vec<_>, Vec<_>) = table.into_iter().unzip();
+    let case_insensitive = features.case_insensitive;
+    let variant = compact_variant(type_name);
+
+    quote! {
+        const NAMES: &[(&str, usize)] = &[#((#keys, #indices)),*];
+
+        if let ::core::option::Option::Some(i) =
+            ::sternum::__private::search_names(NAMES, s, #case_insensitive)
+        {
+            return Ok(#variant);
+        }
+    }
+}
+
+/// Generate the index of `self` in `Sternum::VARIANTS` for a `#[sternum(compact)]` enum.
+///
+/// The variants of a compact enum are indexed by their discriminants, which requires the enum
+/// to be `Copy`. Copying them with `copied` instead of moving them reports a missing `Copy` as a
+/// single error on the enum, instead of errors about moves in the generated code.
+fn compact_index(type_name: &Ident) -> TokenStream {
+    let copied = quote_spanned! {type_name.span()=> ::sternum::__private::copied };
+    quote! { #copied(self) as usize }
+}
+
+/// Generate the variant at index `i` of `Sternum::VARIANTS` for a `#[sternum(compact)]` enum.
+///
+/// See `compact_index` for why it is copied.
+fn compact_variant(type_name: &Ident) -> TokenStream {
+    let copied = quote_spanned! {type_name.span()=> ::sternum::__private::copied };
+    quote! { #copied(&<Self as ::sternum::Sternum>::VARIANTS[i]) }
+}
+
+/// Generate a binary search of the names of the unit variants, ignoring separators and case,
+/// for `#[sternum(loose)]`.
+fn search_names_loose(
+    type_name: &Ident,
+    variants: &[Variant],
+    features: &FeatureSet,
+) -> TokenStream {
+    let mut table: Vec<(String, usize)> = variants
+        .iter()
+        .filter(|variant| matches!(variant.kind, VariantKind::Unit { .. }))
+        .enumerate()
+        .flat_map(|(i, variant)| {
+            variant
+                .names()
+                .map(move |name| (normalize_loose(&name.value), i))
+        })
+        .collect();
+
+    if table.is_empty() {
+        return TokenStream::new();
+    }
+
+    table.sort();
+
+    let (keys, indices): (Vec<_>, Vec<_>) = table.into_iter().unzip();
+    let variant = unit_variant(type_name, variants, features);
+
+    quote! {
+        const NAMES: &[(&str, usize)] = &[#((#keys, #indices)),*];
+
+        if let ::core::option::Option::Some(i) =
+            ::sternum::__private::search_names_loose(NAMES, s)
+        {
+            return Ok(#variant);
+        }
+    }
+}
+
+/// Generate a search of the names of the unit variants for `#[sternum(normalize = ...)]`, which
+/// normalizes the names the first time the enum is parsed and compares them with the normalized
+/// input.
+fn search_names_normalized(
+    type_name: &Ident,
+    variants: &[Variant],
+    features: &FeatureSet,
+    normalize: &syn::Path,
+) -> TokenStream {
+    let table: Vec<(String, usize)> = variants
+        .iter()
+        .filter(|variant| matches!(variant.kind, VariantKind::Unit { .. }))
+        .enumerate()
+        .flat_map(|(i, variant)| variant.names().map(move |name| (name.value, i)))
+        .collect();
+
+    if table.is_empty() {
+        return TokenStream::new();
+    }
+
+    let (names, indices): (Vec<_>, Vec<_>) = table.into_iter().unzip();
+    let variant = unit_variant(type_name, variants, features);
+    let case_insensitive = features.case_insensitive;
+
+    quote! {
+        const NAMES: &[(&str, usize)] = &[#((#names, #indices)),*];
+        static NORMALIZED_NAMES: ::sternum::__private::NormalizedNames =
+            ::sternum::__private::NormalizedNames::new();
+
+        if let ::core::option::Option::Some(i) = NORMALIZED_NAMES.search(
+            NAMES,
+            |name| ::sternum::__private::String::from(&*#normalize(name)),
+            &#normalize(s),
+            #case_insensitive,
+        ) {
+            return Ok(#variant);
+        }
+    }
+}
+
+/// Generate `parse_abbrev` and `min_abbrev`, which accept unambiguous prefixes of the names of
+/// the unit variants.
+fn impl_abbrev(
+    type_name: &Ident,
+    variants: &[Variant],
+    features: &FeatureSet,
+) -> Result<TokenStream, Error> {
+    if let Some(variant) = variants
+        .iter()
+        .find(|variant| matches!(variant.kind, VariantKind::Other))
+    {
+        return Err(Error::new_spanned(
+            variant.ident,
+            "#[sternum(abbrev)] cannot be used with a #[sternum(other)] variant, which accepts every string",
+        ));
+    }
+
+    let key = |name: &str| {
+        if features.case_insensitive {
+            fold_case(name)
+        } else {
+            name.to_owned()
+        }
+    };
+
+    let names: Vec<&str> = variants
+        .iter()
+        .filter_map(|variant| match variant.kind {
+            VariantKind::Unit { ref name } => Some(&**name),
+            _ => None,
+        })
+        .collect();
+    let keys: Vec<String> = names.iter().map(|name| key(name)).collect();
+
+    // Every name and alias, which `FromStr` parses before any abbreviation.
+    let exact: Vec<String> = variants
+        .iter()
+        .flat_map(Variant::names)
+        .map(|name| key(&name.value))
+        .collect();
+
+    // The shortest prefix of each name that no other name starts with and that is not the name
+    // or alias of another variant, or the entire name if there is no such prefix.
+    let min_abbrevs = names.iter().zip(&keys).map(|(name, name_key)| {
+        name.char_indices()
+            .map(|(i, _)| i)
+            .skip(1)
+            .chain(Some(name.len()))
+            .map(|end| &name[..end])
+            .find(|prefix| {
+                let prefix = key(prefix);
+                prefix == *name_key
+                    || (!exact.contains(&prefix)
+                        && keys
+                            .iter()
+                            .filter(|other| other.starts_with(&prefix))
+                            .count()
+                            == 1)
+            })
+            .unwrap_or(name)
+    });
+
+    let mut sorted: Vec<(&String, &str)> = keys.iter().zip(names.iter().copied()).collect();
+    sorted.sort();
+    let (sorted_keys, sorted_names): (Vec<_>, Vec<_>) = sorted.into_iter().unzip();
+    let case_insensitive = features.case_insensitive;
+    let trim = features.trim;
+
+    Ok(quote! {
+        impl #type_name {
+            /// Parse a variant from its name, or from an unambiguous prefix of the name of a
+            /// unit variant.
+            pub fn parse_abbrev(
+                s: &str,
+            ) -> ::core::result::Result<Self, ::sternum::AbbrevError<Self>> {
+                const KEYS: &[&str] = &[#(#sorted_keys),*];
+                const NAMES: &[&str] = &[#(#sorted_names),*];
+
+                ::sternum::__private::parse_abbrev(s, KEYS, NAMES, #case_insensitive, #trim)
+            }
+
+            /// The shortest abbreviation of each of the `Sternum::NAMES` of the enum that
+            /// `parse_abbrev` accepts.
+            pub fn min_abbrev() -> &'static [&'static str] {
+                &[#(#min_abbrevs),*]
+            }
+        }
+    })
+}
+
+/// Normalize a name for `#[sternum(loose)]` by removing its separators and folding its case.
+///
+/// This must be kept in sync with `sternum::__private::search_names_loose`.
+fn normalize_loose(name: &str) -> String {
+    name.chars()
+        .filter(|&c| !matches!(c, '-' | '_' | '.') && !c.is_whitespace())
+        .flat_map(fold::fold_case)
+        .collect()
+}
+
+/// Fold the case of a name for comparing it case-insensitively, the same way that the generated
+/// code folds its input.
+fn fold_case(name: &str) -> String {
+    name.chars().flat_map(fold::fold_case).collect()
+}
+
+/// Generate `TryFrom` implementations for string types that defer to the `FromStr`
+/// implementation.
+fn impl_try_from(type_name: &Ident) -> TokenStream {
+    let alloc_impls = if cfg!(feature = "alloc") {
+        quote! {
+            impl ::core::convert::TryFrom<::sternum::__private::String> for #type_name {
+                type Error = <Self as ::core::str::FromStr>::Err;
+
+                fn try_from(
+                    s: ::sternum::__private::String,
+                ) -> ::core::result::Result<Self, <Self as ::core::str::FromStr>::Err> {
+                    ::core::str::FromStr::from_str(&s)
+                }
+            }
+
+            impl<'a> ::core::convert::TryFrom<::sternum::__private::Cow<'a, str>> for #type_name {
+                type Error = <Self as ::core::str::FromStr>::Err;
+
+                fn try_from(
+                    s: ::sternum::__private::Cow<'a, str>,
+                ) -> ::core::result::Result<Self, <Self as ::core::str::FromStr>::Err> {
+                    ::core::str::FromStr::from_str(&s)
+                }
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    quote! {
+        impl<'a> ::core::convert::TryFrom<&'a str> for #type_name {
+            type Error = <Self as ::core::str::FromStr>::Err;
+
+            fn try_from(
+                s: &'a str,
+            ) -> ::core::result::Result<Self, <Self as ::core::str::FromStr>::Err> {
+                ::core::str::FromStr::from_str(s)
+            }
+        }
+
+        #alloc_impls
+    }
+}
+
+/// Generate serde `Serialize` and `Deserialize` implementations that use the same string form as
+/// the `Display` and `FromStr` implementations.
+fn impl_serde(type_name: &Ident, variants: &[Variant]) -> TokenStream {
+    // Enums with only unit variants can serialize their name directly instead of going through
+    // `Display`.
+    let serialize = if variants
+        .iter()
+        .all(|variant| matches!(variant.kind, VariantKind::Unit { .. }))
+    {
+        quote! { serializer.serialize_str(self.as_str()) }
+    } else {
+        quote! { serializer.collect_str(self) }
+    };
+
+    let expecting = format!("a variant of {}", type_name);
+
+    quote! {
+        const _: () = {
+            use ::sternum::__private::serde;
+
+            impl serde::Serialize for #type_name {
+                fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer,
+                {
+                    #serialize
+                }
+            }
+
+            struct Visitor;
+
+            impl<'de> serde::de::Visitor<'de> for Visitor {
+                type Value = #type_name;
+
+                fn expecting(
+                    &self,
+                    f: &mut ::core::fmt::Formatter,
+                ) -> ::core::fmt::Result {
+                    f.write_str(#expecting)
+                }
+
+                fn visit_str<E>(self, value: &str) -> ::core::result::Result<Self::Value, E>
+                where
+                    E: serde::de::Error,
+                {
+                    match ::core::str::FromStr::from_str(value) {
+                        Ok(value) => Ok(value),
+                        Err(_) => Err(E::unknown_variant(
+                            value,
+                            <#type_name as ::sternum::Sternum>::NAMES,
+                        )),
+                    }
+                }
+            }
+
+            impl<'de> serde::Deserialize<'de> for #type_name {
+                fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+                where
+                    D: serde::Deserializer<'de>,
+                {
+                    deserializer.deserialize_str(Visitor)
+                }
+            }
+        };
+    }
+}
+
+/// Generate a clap `ValueEnum` implementation whose possible values are the names of the unit
+/// variants.
+fn impl_clap(type_name: &Ident, variants: &[Variant]) -> TokenStream {
+    let matches = variants.iter().filter_map(|variant| {
+        let ident = variant.ident;
+        let name = match variant.kind {
+            VariantKind::Unit { ref name } => name,
+            _ => return None,
+        };
+
+        let aliases = if variant.features.aliases.is_empty() {
+            TokenStream::new()
+        } else {
+            let aliases = &variant.features.aliases;
+            quote! { .aliases([#(#aliases),*]) }
+        };
+
+        let help = match variant.doc {
+            Some(ref doc) => quote! { .help(#doc) },
+            None => TokenStream::new(),
+        };
+
+        Some(quote! {
+            #type_name::#ident => ::core::option::Option::Some(
+                ::sternum::__private::clap::builder::PossibleValue::new(#name)
+                    #aliases
+                    #help
+            ),
+        })
+    });
+
+    quote! {
+        impl ::sternum::__private::clap::ValueEnum for #type_name {
+            fn value_variants<'a>() -> &'a [Self] {
+                <Self as ::sternum::Sternum>::VARIANTS
+            }
+
+            #[allow(unreachable_patterns)]
+            fn to_possible_value(
+                &self,
+            ) -> ::core::option::Option<::sternum::__private::clap::builder::PossibleValue> {
+                match self {
                     #(#matches)*
-                    _ => Err(::sternum::UnknownVariantError::new(s)),
+                    _ => ::core::option::Option::None,
                 }
             }
         }
@@ -0,0 +1,243 @@
+// Copyright 2019 Barret Rennie
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Error, Ident, LitStr, Member};
+
+/// A template for displaying and parsing a variant with fields, such as `"L{0}"` or `"{w}x{h}"`.
+pub struct Pattern {
+    segments: Vec<Segment>,
+
+    /// The members of the variant's fields, in declaration order.
+    members: Vec<Member>,
+}
+
+/// A piece of a [`Pattern`][Pattern].
+///
+/// [Pattern]: struct.Pattern.html
+enum Segment {
+    /// Literal text, which is displayed as-is and must be matched exactly when parsing.
+    Literal(String),
+
+    /// The index of a field of the variant.
+    Field(usize),
+}
+
+impl Pattern {
+    /// Parse a pattern, ensuring that it refers to each of the variant's fields exactly once.
+    pub fn parse(lit: &LitStr, fields: &syn::Fields) -> Result<Self, Error> {
+        let members: Vec<Member> = fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| match field.ident {
+                Some(ref ident) => Member::Named(ident.clone()),
+                None => Member::Unnamed(syn::Index {
+                    index: i as u32,
+                    span: lit.span(),
+                }),
+            })
+            .collect();
+
+        if members.is_empty() {
+            return Err(Error::new_spanned(
+                lit,
+                "#[sternum(pattern = ...)] is only supported on variants with fields",
+            ));
+        }
+
+        let value = lit.value();
+        let mut segments = vec![];
+        let mut literal = String::new();
+        let mut chars = value.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    literal.push('{');
+                }
+
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    literal.push('}');
+                }
+
+                '{' => {
+                    let mut name = String::new();
+
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => name.push(c),
+                            None => {
+                                return Err(Error::new_spanned(
+                                    lit,
+                                    "Unterminated field in pattern; expected `}'",
+                                ))
+                            }
+                        }
+                    }
+
+                    let index = members
+                        .iter()
+                        .position(|member| member_name(member) == name)
+                        .ok_or_else(|| {
+                            Error::new_spanned(
+                                lit,
+                                format!("The variant has no field named `{}'", name),
+                            )
+                        })?;
+
+                    if !literal.is_empty() {
+                        segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                    } else if let Some(Segment::Field(prev)) = segments.last() {
+                        return Err(Error::new_spanned(
+                            lit,
+                            format!(
+                                "The fields `{}' and `{}' must be separated by literal text",
+                                member_name(&members[*prev]),
+                                name,
+                            ),
+                        ));
+                    }
+
+                    if segments
+                        .iter()
+                        .any(|segment| matches!(segment, Segment::Field(i) if *i == index))
+                    {
+                        return Err(Error::new_spanned(
+                            lit,
+                            format!("The field `{}' appears more than once in the pattern", name),
+                        ));
+                    }
+
+                    segments.push(Segment::Field(index));
+                }
+
+                '}' => {
+                    return Err(Error::new_spanned(
+                        lit,
+                        "Unmatched `}' in pattern; use `}}' for a literal `}'",
+                    ));
+                }
+
+                c => literal.push(c),
+            }
+        }
+
+        if !literal.is_empty() {
+            segments.push(Segment::Literal(literal));
+        }
+
+        for (i, member) in members.iter().enumerate() {
+            if !segments
+                .iter()
+                .any(|segment| matches!(segment, Segment::Field(j) if *j == i))
+            {
+                return Err(Error::new_spanned(
+                    lit,
+                    format!(
+                        "The field `{}' does not appear in the pattern",
+                        member_name(member)
+                    ),
+                ));
+            }
+        }
+
+        Ok(Pattern { segments, members })
+    }
+
+    /// Generate the match arm for the variant in the `Display` implementation.
+    pub fn display_arm(&self, type_name: &Ident, ident: &Ident) -> TokenStream {
+        let members = &self.members;
+        let bindings = self.bindings();
+
+        let writes = self.segments.iter().map(|segment| match segment {
+            Segment::Literal(literal) => quote! {
+                f.write_str(#literal)?;
+            },
+            Segment::Field(i) => {
+                let binding = &bindings[*i];
+                quote! {
+                    ::core::fmt::Display::fmt(#binding, f)?;
+                }
+            }
+        });
+
+        quote! {
+            #type_name::#ident { #(#members: #bindings),* } => {
+                #(#writes)*
+                Ok(())
+            }
+        }
+    }
+
+    /// Generate a function named `fn_name` that attempts to parse the variant from a string.
+    pub fn parse_fn(&self, fn_name: &Ident, type_name: &Ident, ident: &Ident) -> TokenStream {
+        let members = &self.members;
+        let bindings = self.bindings();
+
+        let mut segments = &self.segments[..];
+        let mut steps = vec![];
+
+        if let Some((Segment::Literal(prefix), rest)) = segments.split_first() {
+            steps.push(quote! { let s = s.strip_prefix(#prefix)?; });
+            segments = rest;
+        }
+
+        if let Some((Segment::Literal(suffix), rest)) = segments.split_last() {
+            steps.push(quote! { let s = s.strip_suffix(#suffix)?; });
+            segments = rest;
+        }
+
+        // The remaining segments alternate between fields and literals, beginning and ending with
+        // a field. Each field but the last ends at the first occurrence of the literal that
+        // follows it, and the last field consumes the rest of the string.
+        let mut iter = segments.iter();
+        while let Some(segment) = iter.next() {
+            let binding = match segment {
+                Segment::Field(i) => &bindings[*i],
+                Segment::Literal(..) => unreachable!(),
+            };
+
+            match iter.next() {
+                Some(Segment::Literal(sep)) => steps.push(quote! {
+                    let (field, s) = s.split_once(#sep)?;
+                    let #binding = ::core::str::FromStr::from_str(field).ok()?;
+                }),
+                Some(Segment::Field(..)) => unreachable!(),
+                None => steps.push(quote! {
+                    let #binding = ::core::str::FromStr::from_str(s).ok()?;
+                }),
+            }
+        }
+
+        quote! {
+            #[allow(non_snake_case)]
+            fn #fn_name(s: &str) -> ::core::option::Option<#type_name> {
+                #(#steps)*
+                ::core::option::Option::Some(#type_name::#ident { #(#members: #bindings),* })
+            }
+        }
+    }
+
+    /// The names of the local bindings for each field.
+    fn bindings(&self) -> Vec<Ident> {
+        (0..self.members.len())
+            .map(|i| format_ident!("__sternum_field{}", i))
+            .collect()
+    }
+}
+
+fn member_name(member: &Member) -> String {
+    match member {
+        Member::Named(ident) => ident.to_string(),
+        Member::Unnamed(index) => index.index.to_string(),
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright 2019 Barret Rennie
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::{BTreeMap, VecDeque};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// A state of an Aho-Corasick automaton.
+#[derive(Default)]
+struct State {
+    /// The state to go to on each byte.
+    transitions: BTreeMap<u8, usize>,
+
+    /// The state for the longest proper suffix of this state that is also a state.
+    fail: usize,
+
+    /// The length of the keys that lead to this state.
+    depth: usize,
+
+    /// The index and length of the longest key that the input ends with in this state.
+    output: Option<(usize, usize)>,
+
+    /// The state whose output is the longest key that is shorter than the output of this state
+    /// and that the input also ends with, or the start state if there is none.
+    dict_suffix: usize,
+}
+
+/// Generate the `STATES` and `TRANSITIONS` of an Aho-Corasick automaton that finds the keys,
+/// for `sternum::__private::FindIter`.
+///
+/// The automaton is a trie of the keys, where each state also records the state to fall back to
+/// when the next byte has no transition, the longest key that ends there, and the state with the
+/// next shorter key that ends there. Empty keys are never found.
+pub fn automaton(keys: &[String]) -> TokenStream {
+    let mut states = vec![State::default()];
+
+    for (index, key) in keys.iter().enumerate() {
+        if key.is_empty() {
+            continue;
+        }
+
+        let mut state = 0;
+
+        for (depth, &byte) in key.as_bytes().iter().enumerate() {
+            state = match states[state].transitions.get(&byte) {
+                Some(&next) => next,
+                None => {
+                    states.push(State {
+                        depth: depth + 1,
+                        ..State::default()
+                    });
+
+                    let next = states.len() - 1;
+                    states[state].transitions.insert(byte, next);
+                    next
+                }
+            };
+        }
+
+        states[state].output = Some((index, key.len()));
+    }
+
+    // The fall back state of each state is shallower than the state itself, so visiting the
+    // states in breadth-first order computes it before it is needed.
+    let mut queue: VecDeque<usize> = states[0].transitions.values().copied().collect();
+
+    while let Some(state) = queue.pop_front() {
+        let transitions: Vec<(u8, usize)> = states[state]
+            .transitions
+            .iter()
+            .map(|(&byte, &next)| (byte, next))
+            .collect();
+
+        for (byte, next) in transitions {
+            let mut fail = states[state].fail;
+
+            let fail = loop {
+                if let Some(&target) = states[fail].transitions.get(&byte) {
+                    break target;
+                }
+
+                if fail == 0 {
+                    break 0;
+                }
+
+                fail = states[fail].fail;
+            };
+
+            states[next].fail = fail;
+            if states[next].output.is_none() {
+                states[next].output = states[fail].output;
+            }
+
+            queue.push_back(next);
+        }
+    }
+
+    // Each state on the fall back chain has an output no longer than the one before it, so the
+    // next shorter output is the first one on the chain that is shorter.
+    for state in 0..states.len() {
+        if let Some((_, len)) = states[state].output {
+            let mut suffix = states[state].fail;
+
+            while suffix != 0 && !matches!(states[suffix].output, Some((_, l)) if l < len) {
+                suffix = states[suffix].fail;
+            }
+
+            states[state].dict_suffix = suffix;
+        }
+    }
+
+    let mut transitions = vec![];
+    let states = states.iter().map(|state| {
+        let first = transitions.len();
+        transitions.extend(
+            state
+                .transitions
+                .iter()
+                .map(|(&byte, &next)| quote! { (#byte, #next) }),
+        );
+        let len = transitions.len() - first;
+
+        let fail = state.fail;
+        let depth = state.depth;
+        let dict_suffix = state.dict_suffix;
+        let output = match state.output {
+            Some((index, len)) => quote! { ::core::option::Option::Some((#index, #len)) },
+            None => quote! { ::core::option::Option::None },
+        };
+
+        quote! {
+            ::sternum::__private::SearchState {
+                transitions: (#first, #len),
+                fail: #fail,
+                depth: #depth,
+                output: #output,
+                dict_suffix: #dict_suffix,
+            }
+        }
+    });
+    let states: Vec<_> = states.collect();
+
+    quote! {
+        const STATES: &[::sternum::__private::SearchState] = &[#(#states),*];
+        const TRANSITIONS: &[(u8, usize)] = &[#(#transitions),*];
+    }
+}
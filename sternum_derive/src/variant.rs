@@ -0,0 +1,364 @@
+// Copyright 2019 Barret Rennie
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use quote::ToTokens;
+use syn::{Error, Ident};
+
+use crate::error::ErrorList;
+use crate::features::{parse_variant_features, FeatureSet, VariantFeatureSet};
+use crate::pattern::Pattern;
+
+/// A variant of the enum, along with its features.
+pub struct Variant<'a> {
+    pub ident: &'a Ident,
+    pub features: VariantFeatureSet,
+    pub kind: VariantKind,
+
+    /// The first paragraph of the variant's doc comment, if it has one.
+    pub doc: Option<String>,
+}
+
+/// How a variant is displayed and parsed.
+pub enum VariantKind {
+    /// A unit variant, which is displayed and parsed as a fixed name.
+    Unit {
+        /// The name of the variant, after scoping, transforms, and renames have been applied.
+        name: String,
+    },
+
+    /// A single-field tuple variant marked with `#[sternum(other)]`, which captures any string
+    /// that does not match another variant.
+    Other,
+
+    /// A variant with fields marked with `#[sternum(pattern = "...")]`, which is displayed and
+    /// parsed according to its pattern.
+    Pattern(Pattern),
+
+    /// A single-field tuple variant marked with `#[sternum(nested)]`, whose field is another
+    /// Sternum enum. It is displayed and parsed as its prefix, the separator, and then the inner
+    /// value.
+    Nested {
+        /// The name of the variant, after scoping, transforms, and renames have been applied.
+        prefix: String,
+        ty: syn::Type,
+    },
+
+    /// A single-field tuple variant marked with `#[sternum(flatten)]`, whose field is another
+    /// Sternum enum. It is displayed and parsed exactly as the inner value.
+    Flatten { ty: syn::Type },
+}
+
+/// A name that a variant can be parsed from.
+pub struct Name<'a> {
+    pub value: String,
+
+    /// The tokens that the name originates from.
+    ///
+    /// This is used for reporting errors about the name.
+    pub tokens: &'a dyn ToTokens,
+
+    /// Whether or not the name is an alias, which is only used by `FromStr`.
+    pub alias: bool,
+}
+
+impl<'a> Variant<'a> {
+    /// Iterate over the name of the variant followed by its aliases.
+    ///
+    /// Variants that do not have a fixed name have no names.
+    pub fn names(&self) -> impl Iterator<Item = Name<'_>> {
+        let name = match self.kind {
+            VariantKind::Unit { ref name } => Some(Name {
+                value: name.clone(),
+                tokens: match self.features.rename {
+                    Some(ref rename) => rename,
+                    None => self.ident,
+                },
+                alias: false,
+            }),
+            VariantKind::Other
+            | VariantKind::Pattern(..)
+            | VariantKind::Nested { .. }
+            | VariantKind::Flatten { .. } => None,
+        };
+
+        let aliases = self.features.aliases.iter().map(|alias| Name {
+            value: alias.value(),
+            tokens: alias,
+            alias: true,
+        });
+
+        name.into_iter().chain(aliases)
+    }
+}
+
+/// Parse the features of each variant, validate their shapes, and compute their names.
+pub fn collect_variants<'a, I>(
+    type_name: &Ident,
+    variants: I,
+    features: &FeatureSet,
+) -> Result<Vec<Variant<'a>>, ErrorList>
+where
+    I: IntoIterator<Item = &'a syn::Variant>,
+{
+    let mut errors = vec![];
+    let mut collected = vec![];
+    let mut other: Option<&Ident> = None;
+
+    for variant in variants {
+        let variant_features = match parse_variant_features(&variant.attrs) {
+            Ok(variant_features) => variant_features,
+            Err(ErrorList(mut e)) => {
+                errors.append(&mut e);
+                continue;
+            }
+        };
+
+        let kind = match variant_kind(type_name, variant, &variant_features, features) {
+            Ok(kind) => kind,
+            Err(mut e) => {
+                errors.append(&mut e);
+                continue;
+            }
+        };
+
+        if let VariantKind::Other = kind {
+            if let Some(prev_ident) = other {
+                errors.push(Error::new_spanned(
+                    variant,
+                    format!(
+                        "Only one variant can be marked #[sternum(other)], but `{}' already is",
+                        prev_ident
+                    ),
+                ));
+            }
+
+            other = Some(&variant.ident);
+        }
+
+        collected.push(Variant {
+            ident: &variant.ident,
+            features: variant_features,
+            kind,
+            doc: variant_doc(&variant.attrs),
+        });
+    }
+
+    if errors.is_empty() {
+        Ok(collected)
+    } else {
+        Err(ErrorList(errors))
+    }
+}
+
+/// Determine the kind of a variant from its shape and features.
+fn variant_kind(
+    type_name: &Ident,
+    variant: &syn::Variant,
+    variant_features: &VariantFeatureSet,
+    features: &FeatureSet,
+) -> Result<VariantKind, Vec<Error>> {
+    let mut errors = vec![];
+
+    let single_field = match variant.fields {
+        syn::Fields::Unnamed(ref fields) if fields.unnamed.len() == 1 => {
+            Some(&fields.unnamed[0].ty)
+        }
+        _ => None,
+    };
+
+    let name = || match variant_features.rename {
+        Some(ref rename) => rename.value(),
+        None => variant_name(type_name, &variant.ident, features),
+    };
+
+    let kind = if variant_features.other {
+        if let Some(ref rename) = variant_features.rename {
+            errors.push(Error::new_spanned(
+                rename,
+                "A #[sternum(other)] variant cannot be renamed",
+            ));
+        }
+
+        if let Some(ref pattern) = variant_features.pattern {
+            errors.push(Error::new_spanned(
+                pattern,
+                "A #[sternum(other)] variant cannot have a pattern",
+            ));
+        }
+
+        if variant_features.nested {
+            errors.push(Error::new_spanned(
+                variant,
+                "A #[sternum(other)] variant cannot be #[sternum(nested)]",
+            ));
+        }
+
+        if variant_features.flatten {
+            errors.push(Error::new_spanned(
+                variant,
+                "A #[sternum(other)] variant cannot be #[sternum(flatten)]",
+            ));
+        }
+
+        errors.extend(variant_features.aliases.iter().map(|alias| {
+            Error::new_spanned(alias, "A #[sternum(other)] variant cannot have aliases")
+        }));
+
+        if single_field.is_none() {
+            errors.push(Error::new_spanned(
+                variant,
+                "#[sternum(other)] is only supported on tuple variants with a single field (like Unknown(String))",
+            ));
+        }
+
+        VariantKind::Other
+    } else if let Some(ref pattern) = variant_features.pattern {
+        if let Some(ref rename) = variant_features.rename {
+            errors.push(Error::new_spanned(
+                rename,
+                "A variant with a #[sternum(pattern = ...)] cannot be renamed",
+            ));
+        }
+
+        if variant_features.nested {
+            errors.push(Error::new_spanned(
+                variant,
+                "A variant with a #[sternum(pattern = ...)] cannot be #[sternum(nested)]",
+            ));
+        }
+
+        if variant_features.flatten {
+            errors.push(Error::new_spanned(
+                variant,
+                "A variant with a #[sternum(pattern = ...)] cannot be #[sternum(flatten)]",
+            ));
+        }
+
+        errors.extend(variant_features.aliases.iter().map(|alias| {
+            Error::new_spanned(
+                alias,
+                "A variant with a #[sternum(pattern = ...)] cannot have aliases",
+            )
+        }));
+
+        match Pattern::parse(pattern, &variant.fields) {
+            Ok(pattern) => VariantKind::Pattern(pattern),
+            Err(e) => return Err(vec![e]),
+        }
+    } else if variant_features.nested {
+        if variant_features.flatten {
+            errors.push(Error::new_spanned(
+                variant,
+                "A #[sternum(nested)] variant cannot be #[sternum(flatten)]",
+            ));
+        }
+
+        errors.extend(variant_features.aliases.iter().map(|alias| {
+            Error::new_spanned(alias, "A #[sternum(nested)] variant cannot have aliases")
+        }));
+
+        match single_field {
+            Some(ty) => VariantKind::Nested {
+                prefix: name(),
+                ty: ty.clone(),
+            },
+            None => {
+                errors.push(Error::new_spanned(
+                    variant,
+                    "#[sternum(nested)] is only supported on tuple variants with a single field (like Tcp(TcpEvent))",
+                ));
+                return Err(errors);
+            }
+        }
+    } else if variant_features.flatten {
+        if let Some(ref rename) = variant_features.rename {
+            errors.push(Error::new_spanned(
+                rename,
+                "A #[sternum(flatten)] variant cannot be renamed",
+            ));
+        }
+
+        errors.extend(variant_features.aliases.iter().map(|alias| {
+            Error::new_spanned(alias, "A #[sternum(flatten)] variant cannot have aliases")
+        }));
+
+        match single_field {
+            Some(ty) => VariantKind::Flatten { ty: ty.clone() },
+            None => {
+                errors.push(Error::new_spanned(
+                    variant,
+                    "#[sternum(flatten)] is only supported on tuple variants with a single field (like Tcp(TcpEvent))",
+                ));
+                return Err(errors);
+            }
+        }
+    } else if let syn::Fields::Unit = variant.fields {
+        VariantKind::Unit { name: name() }
+    } else {
+        errors.push(Error::new_spanned(
+            variant,
+            "Sternum only supports unit enum variants (like Option::None)",
+        ));
+        return Err(errors);
+    };
+
+    if errors.is_empty() {
+        Ok(kind)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Compute the name of a variant that has not been renamed.
+fn variant_name(type_name: &Ident, ident: &Ident, features: &FeatureSet) -> String {
+    let transform = |name: String| match features.transform {
+        Some(trans) => trans.apply(&name),
+        None => name,
+    };
+
+    if features.scoped {
+        format!(
+            "{}{}{}",
+            transform(type_name.to_string()),
+            features.separator(),
+            transform(ident.to_string()),
+        )
+    } else {
+        transform(ident.to_string())
+    }
+}
+
+/// Collect the first paragraph of a doc comment into a single line.
+fn variant_doc(attrs: &[syn::Attribute]) -> Option<String> {
+    let lines = attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("doc"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(syn::Meta::NameValue(syn::MetaNameValue {
+                lit: syn::Lit::Str(ref lit),
+                ..
+            })) => Some(lit.value()),
+            _ => None,
+        })
+        .flat_map(|doc| {
+            // `///` on its own is an empty doc attribute, which has no lines but still ends
+            // the paragraph.
+            doc.split('\n')
+                .map(|line| line.trim().to_owned())
+                .collect::<Vec<_>>()
+        })
+        .skip_while(|line| line.is_empty())
+        .take_while(|line| !line.is_empty())
+        .collect::<Vec<_>>();
+
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join(" "))
+    }
+}